@@ -0,0 +1,45 @@
+use crate::ParseError;
+
+/// What a part of a puzzle answers: a value to print, or for puzzles that can
+/// only tell while solving, why the input has no answer.
+pub trait Answer {
+    fn render(&self) -> Result<String, ParseError>;
+}
+
+macro_rules! display_answer {
+    ($($t:ty),*) => {
+        $(
+            impl Answer for $t {
+                fn render(&self) -> Result<String, ParseError> {
+                    Ok(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+display_answer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, String);
+
+impl<T: Answer> Answer for Result<T, ParseError> {
+    fn render(&self) -> Result<String, ParseError> {
+        match self {
+            Ok(answer) => answer.render(),
+            Err(err) => Err(err.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        assert_eq!(142u32.render(), Ok("142".to_string()));
+        let answer: Result<u64, ParseError> = Ok(7);
+        assert_eq!(answer.render(), Ok("7".to_string()));
+        let err = ParseError::new(3, 1, "a digit", "abc", "abc");
+        let answer: Result<u32, ParseError> = Err(err.clone());
+        assert_eq!(answer.render(), Err(err));
+    }
+}
@@ -0,0 +1,162 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// A problem found while parsing a puzzle input.
+///
+/// Errors carry the whole offending line so they can be rendered with a caret
+/// under the text that was not understood, see [`ParseError::render`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The input file, once known to the caller.
+    pub file: Option<PathBuf>,
+    /// The 1-based line of the input the problem was found on.
+    pub line: usize,
+    /// The 1-based column, in characters, of the offending text.
+    pub column: usize,
+    /// What the parser expected to find, eg `a number`.
+    pub expected: String,
+    /// The offending text, empty when the line ended too early.
+    pub found: String,
+    /// The full text of the offending line.
+    pub text: String,
+}
+
+impl ParseError {
+    pub fn new<E: Into<String>>(
+        line: usize,
+        column: usize,
+        expected: E,
+        found: &str,
+        text: &str,
+    ) -> ParseError {
+        ParseError {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+            found: found.to_string(),
+            text: text.to_string(),
+        }
+    }
+
+    /// Attaches the input file the error was found in.
+    pub fn with_file<P: AsRef<Path>>(mut self, file: P) -> ParseError {
+        self.file = Some(file.as_ref().to_path_buf());
+        self
+    }
+
+    /// Describes what was found, quoting the offending text.
+    fn describe_found(&self) -> String {
+        if self.found.is_empty() {
+            "end of line".to_string()
+        } else {
+            format!("{:?}", self.found)
+        }
+    }
+
+    /// The position of the error, eg `input.txt:4:7` or `line 4, column 7`.
+    fn location(&self) -> String {
+        match &self.file {
+            Some(file) => format!("{}:{}:{}", file.display(), self.line, self.column),
+            None => format!("line {}, column {}", self.line, self.column),
+        }
+    }
+
+    /// Renders the error as a multi-line diagnostic, quoting the offending
+    /// line with a caret under the text that was not understood.
+    ///
+    /// ```text
+    /// error: expected a number, found "x3"
+    ///   --> day-05/input/input.txt:4:7
+    ///   |
+    /// 4 | 50 98 x3
+    ///   |       ^^
+    /// ```
+    pub fn render(&self) -> String {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let padding = " ".repeat(self.column.saturating_sub(1));
+        let carets = "^".repeat(self.found.chars().count().max(1));
+
+        format!(
+            "error: expected {}, found {}\n{} --> {}\n{} |\n{} | {}\n{} | {}{}\n",
+            self.expected,
+            self.describe_found(),
+            gutter,
+            self.location(),
+            gutter,
+            self.line,
+            self.text,
+            gutter,
+            padding,
+            carets,
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: expected {}, found {}",
+            self.location(),
+            self.expected,
+            self.describe_found()
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let err = ParseError::new(3, 7, "a number", "x3", "50 98 x3");
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 7: expected a number, found \"x3\""
+        );
+        let err = err.with_file("input.txt");
+        assert_eq!(
+            err.to_string(),
+            "input.txt:3:7: expected a number, found \"x3\""
+        );
+    }
+
+    #[test]
+    fn test_display_end_of_line() {
+        let err = ParseError::new(1, 7, "`:`", "", "Card 1");
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 7: expected `:`, found end of line"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let err = ParseError::new(4, 7, "a number", "x3", "50 98 x3").with_file("input.txt");
+        let expected = "\
+error: expected a number, found \"x3\"
+  --> input.txt:4:7
+  |
+4 | 50 98 x3
+  |       ^^
+";
+        assert_eq!(err.render(), expected);
+    }
+
+    #[test]
+    fn test_render_wide_gutter() {
+        let err = ParseError::new(12, 7, "`:`", "", "Card 1");
+        let expected = "\
+error: expected `:`, found end of line
+   --> line 12, column 7
+   |
+12 | Card 1
+   |       ^
+";
+        assert_eq!(err.render(), expected);
+    }
+}
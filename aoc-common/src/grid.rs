@@ -0,0 +1,381 @@
+use std::collections::VecDeque;
+use std::ops::{Index, IndexMut};
+
+/// The four orthogonal neighbours, clockwise from north.
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// All eight neighbours, clockwise from north.
+const SURROUNDING: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// Which cells touch each other when labelling regions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Cells touch their north, east, south and west neighbours.
+    Four,
+    /// Cells touch all eight neighbours, diagonals included.
+    Eight,
+}
+
+/// A set of touching cells that all matched the same predicate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// The positions of the cells of the region, row by row.
+    pub cells: Vec<(usize, usize)>,
+    /// The top left corner of the bounding box of the region.
+    pub min: (usize, usize),
+    /// The bottom right corner of the bounding box of the region, included.
+    pub max: (usize, usize),
+}
+
+impl Region {
+    pub fn size(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn width(&self) -> usize {
+        self.max.0 - self.min.0 + 1
+    }
+
+    pub fn height(&self) -> usize {
+        self.max.1 - self.min.1 + 1
+    }
+}
+
+/// The regions found by `Grid::components`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    /// The regions, ordered by the first of their cells row by row.
+    pub regions: Vec<Region>,
+    /// The index in `regions` of the region every cell belongs to.
+    pub labels: Grid<Option<usize>>,
+}
+
+/// A rectangular grid of cells stored row by row.
+///
+/// Positions are `(x, y)` pairs, `x` being the column counted from the left
+/// and `y` the row counted from the top.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells listed row by row.
+    ///
+    /// Panics if there isn't exactly `width * height` cells.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from its rows.
+    ///
+    /// Panics if the rows are not all as wide as the first one.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let width = rows.first().map_or(0, |x| x.len());
+        let height = rows.len();
+        let mut cells: Vec<T> = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            assert_eq!(row.len(), width, "row {} is not {} cells wide", y, width);
+            cells.extend(row);
+        }
+        Grid::new(width, height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the cell at `(x, y)`, or `None` outside the grid.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the cell at `(x, y)`, or `None` outside the grid.
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over the positions north, east, south and west of `(x, y)`
+    /// that are inside the grid.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, &ORTHOGONAL)
+    }
+
+    /// Iterates over the positions of the up to eight cells touching `(x, y)`,
+    /// diagonals included, that are inside the grid.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, &SURROUNDING)
+    }
+
+    fn offsets(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let x = x.checked_add_signed(*dx)?;
+            let y = y.checked_add_signed(*dy)?;
+            (x < self.width && y < self.height).then_some((x, y))
+        })
+    }
+
+    /// Returns row `y`, from left to right.
+    ///
+    /// Panics if `y` is outside the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {} is outside the grid", y);
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Iterates over the rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Iterates over column `x` from top to bottom.
+    ///
+    /// Panics if `x` is outside the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(x < self.width, "column {} is outside the grid", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Iterates over every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Builds a grid of the same size by transforming every cell.
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Labels the regions of touching cells for which `predicate` is true.
+    pub fn components<F: Fn(&T) -> bool>(
+        &self,
+        connectivity: Connectivity,
+        predicate: F,
+    ) -> Components {
+        let mut regions: Vec<Region> = Vec::new();
+        let mut labels: Grid<Option<usize>> = self.map(|_| None);
+
+        for start in self.positions() {
+            if labels[start].is_some() || !predicate(&self[start]) {
+                continue;
+            }
+
+            let label = regions.len();
+            let mut cells: Vec<(usize, usize)> = Vec::new();
+            let mut queue: VecDeque<(usize, usize)> = VecDeque::from([start]);
+            labels[start] = Some(label);
+
+            while let Some((x, y)) = queue.pop_front() {
+                cells.push((x, y));
+                let neighbours = match connectivity {
+                    Connectivity::Four => self.offsets(x, y, &ORTHOGONAL),
+                    Connectivity::Eight => self.offsets(x, y, &SURROUNDING),
+                };
+                for next in neighbours {
+                    if labels[next].is_none() && predicate(&self[next]) {
+                        labels[next] = Some(label);
+                        queue.push_back(next);
+                    }
+                }
+            }
+
+            cells.sort_by_key(|&(x, y)| (y, x));
+            let min = (
+                cells.iter().map(|x| x.0).min().unwrap(),
+                cells.iter().map(|x| x.1).min().unwrap(),
+            );
+            let max = (
+                cells.iter().map(|x| x.0).max().unwrap(),
+                cells.iter().map(|x| x.1).max().unwrap(),
+            );
+            regions.push(Region { cells, min, max });
+        }
+
+        Components { regions, labels }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", x, y))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0 1 2
+    /// 3 4 5
+    fn grid() -> Grid<u32> {
+        Grid::from_rows(vec![vec![0, 1, 2], vec![3, 4, 5]])
+    }
+
+    #[test]
+    fn test_get() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(2, 0), Some(&2));
+        assert_eq!(grid.get(0, 1), Some(&3));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+        assert_eq!(grid[(1, 1)], 4);
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut grid = grid();
+        *grid.get_mut(1, 0).unwrap() = 9;
+        grid[(2, 1)] = 8;
+        assert_eq!(grid.row(0), &[0, 9, 2]);
+        assert_eq!(grid.row(1), &[3, 4, 8]);
+        assert!(grid.get_mut(5, 5).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the grid")]
+    fn test_index_outside() {
+        let _ = grid()[(3, 1)];
+    }
+
+    #[test]
+    #[should_panic(expected = "not 3 cells wide")]
+    fn test_ragged_rows() {
+        Grid::from_rows(vec![vec![0, 1, 2], vec![3, 4]]);
+    }
+
+    #[test]
+    fn test_neighbours4() {
+        let grid = grid();
+        let corner: Vec<(usize, usize)> = grid.neighbours4(0, 0).collect();
+        assert_eq!(corner, vec![(1, 0), (0, 1)]);
+        let edge: Vec<(usize, usize)> = grid.neighbours4(1, 1).collect();
+        assert_eq!(edge, vec![(1, 0), (2, 1), (0, 1)]);
+    }
+
+    #[test]
+    fn test_neighbours8() {
+        let grid = grid();
+        let corner: Vec<(usize, usize)> = grid.neighbours8(2, 1).collect();
+        assert_eq!(corner, vec![(2, 0), (1, 1), (1, 0)]);
+        let edge: Vec<(usize, usize)> = grid.neighbours8(1, 0).collect();
+        assert_eq!(edge, vec![(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+        let rows: Vec<&[u32]> = grid.rows().collect();
+        assert_eq!(rows, vec![&[0, 1, 2][..], &[3, 4, 5][..]]);
+        let column: Vec<&u32> = grid.column(2).collect();
+        assert_eq!(column, vec![&2, &5]);
+    }
+
+    #[test]
+    fn test_positions_and_map() {
+        let grid = grid();
+        let positions: Vec<(usize, usize)> = grid.positions().collect();
+        assert_eq!(
+            positions,
+            vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]
+        );
+
+        let doubled = grid.map(|x| x * 2);
+        assert_eq!(doubled.row(1), &[6, 8, 10]);
+    }
+
+    #[test]
+    fn test_components() {
+        // # . #
+        // . # #
+        // # . .
+        let grid = Grid::from_rows(vec![
+            vec!['#', '.', '#'],
+            vec!['.', '#', '#'],
+            vec!['#', '.', '.'],
+        ]);
+
+        let four = grid.components(Connectivity::Four, |x| *x == '#');
+        let sizes: Vec<usize> = four.regions.iter().map(|x| x.size()).collect();
+        assert_eq!(sizes, vec![1, 3, 1]);
+        assert_eq!(four.regions[1].cells, vec![(2, 0), (1, 1), (2, 1)]);
+        assert_eq!((four.regions[1].min, four.regions[1].max), ((1, 0), (2, 1)));
+        assert_eq!(four.labels[(1, 1)], Some(1));
+        assert_eq!(four.labels[(1, 0)], None);
+
+        let eight = grid.components(Connectivity::Eight, |x| *x == '#');
+        assert_eq!(eight.regions.len(), 1);
+        let region = &eight.regions[0];
+        assert_eq!((region.size(), region.width(), region.height()), (5, 3, 3));
+        assert_eq!(eight.labels[(0, 2)], Some(0));
+    }
+
+    #[test]
+    fn test_components_none() {
+        let components = grid().components(Connectivity::Eight, |x| *x > 9);
+        assert!(components.regions.is_empty());
+        assert!(components
+            .labels
+            .positions()
+            .all(|x| components.labels[x].is_none()));
+    }
+
+    #[test]
+    fn test_empty() {
+        let grid: Grid<u32> = Grid::from_rows(Vec::new());
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.positions().count(), 0);
+        assert_eq!(grid.rows().count(), 0);
+    }
+}
@@ -0,0 +1,40 @@
+use std::io;
+use std::path::Path;
+
+/// Reads a puzzle input file into a string, returning any error to the caller.
+pub fn try_read_input<P: AsRef<Path>>(path: P) -> io::Result<String> {
+    std::fs::read_to_string(path)
+}
+
+/// Reads a puzzle input file into a string.
+///
+/// Panics with the offending path when the file can not be read, as there is
+/// nothing a puzzle can do without its input.
+pub fn read_input<P: AsRef<Path>>(path: P) -> String {
+    let path = path.as_ref();
+    try_read_input(path)
+        .unwrap_or_else(|err| panic!("Failed to read file {}: {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_input() {
+        let input = read_input("Cargo.toml");
+        assert!(input.starts_with("[package]"));
+    }
+
+    #[test]
+    fn test_try_read_missing_input() {
+        let err = try_read_input("does-not-exist.txt").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to read file does-not-exist.txt")]
+    fn test_read_missing_input() {
+        read_input("does-not-exist.txt");
+    }
+}
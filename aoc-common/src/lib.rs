@@ -0,0 +1,20 @@
+//! Shared helpers for the Advent of Code 2023 solutions.
+//!
+//! Every day loads its puzzle input, parses a handful of common line shapes
+//! and prints its answers the same way, so that logic lives here and the day
+//! crates only contain the puzzle itself.
+
+pub mod answer;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod parse;
+#[doc(hidden)]
+pub mod random;
+pub mod solution;
+
+pub use answer::Answer;
+pub use error::ParseError;
+pub use grid::{Connectivity, Grid};
+pub use input::read_input;
+pub use solution::{Parsed, Puzzle, Solution};
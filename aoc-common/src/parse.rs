@@ -0,0 +1,171 @@
+use crate::ParseError;
+use std::str::FromStr;
+
+/// One line of a puzzle input, remembering its 1-based line number so parse
+/// errors can point at the offending text.
+///
+/// Every `part` handed to a `Line` method must be a slice of [`Line::text`],
+/// which is what lets the error work out its column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Line<'a> {
+        Line { number, text }
+    }
+
+    /// Returns an error pointing at the whole line.
+    pub fn error<E: Into<String>>(&self, expected: E) -> ParseError {
+        self.error_at(self.text, expected)
+    }
+
+    /// Returns an error pointing at `part` of the line.
+    pub fn error_at<E: Into<String>>(&self, part: &str, expected: E) -> ParseError {
+        let column = self.text[..self.offset(part)].chars().count() + 1;
+        ParseError::new(self.number, column, expected, part, self.text)
+    }
+
+    /// Returns an error pointing just after `part`, for text that ended early.
+    pub fn error_after<E: Into<String>>(&self, part: &str, expected: E) -> ParseError {
+        let end = self.offset(part) + part.len();
+        self.error_at(&self.text[end..end], expected)
+    }
+
+    /// Splits `part` on the first `delimiter`, eg `"Card 1: 41 48"` on `:`.
+    pub fn split_once(
+        &self,
+        part: &'a str,
+        delimiter: char,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        part.split_once(delimiter)
+            .ok_or_else(|| self.error_after(part, format!("`{}`", delimiter)))
+    }
+
+    /// Parses `part` of the line as a number.
+    pub fn number<T: FromStr>(&self, part: &str) -> Result<T, ParseError> {
+        part.trim()
+            .parse::<T>()
+            .map_err(|_| self.error_at(part.trim(), "a number"))
+    }
+
+    /// Parses every whitespace separated value of `part` as a number.
+    pub fn numbers<T: FromStr>(&self, part: &str) -> Result<Vec<T>, ParseError> {
+        part.split_whitespace().map(|x| self.number(x)).collect()
+    }
+
+    /// The byte offset of `part` within the line.
+    fn offset(&self, part: &str) -> usize {
+        let offset = (part.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        assert!(
+            offset <= self.text.len(),
+            "part {:?} is not a slice of line {:?}",
+            part,
+            self.text
+        );
+        offset
+    }
+}
+
+/// Returns every line of the input with its line number.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, text)| Line::new(idx + 1, text))
+}
+
+/// Returns an empty line just past the end of the input, for errors about
+/// something missing from the input as a whole.
+pub fn end_of_input(input: &str) -> Line<'_> {
+    Line::new(input.lines().count() + 1, "")
+}
+
+/// Splits an input into its blank line separated blocks.
+///
+/// Leading and trailing blank lines are ignored and every block is returned
+/// as its non-empty lines, trimmed and numbered.
+pub fn blocks(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks: Vec<Vec<Line>> = Vec::new();
+    let mut current: Vec<Line> = Vec::new();
+
+    for line in lines(input) {
+        let line = Line::new(line.number, line.text.trim());
+        if line.text.is_empty() {
+            if !current.is_empty() {
+                blocks.push(current);
+                current = Vec::new();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocks() {
+        let input = "\nseeds: 1 2\n\nseed-to-soil map:\n50 98 2\n52 50 48\n\n\n";
+        let blocks = blocks(input);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0], vec![Line::new(2, "seeds: 1 2")]);
+        let texts: Vec<&str> = blocks[1].iter().map(|x| x.text).collect();
+        assert_eq!(texts, vec!["seed-to-soil map:", "50 98 2", "52 50 48"]);
+        assert_eq!(blocks[1][2].number, 6);
+    }
+
+    #[test]
+    fn test_lines() {
+        let lines: Vec<Line> = lines("a\nb").collect();
+        assert_eq!(lines, vec![Line::new(1, "a"), Line::new(2, "b")]);
+        assert_eq!(end_of_input("a\nb"), Line::new(3, ""));
+    }
+
+    #[test]
+    fn test_line_numbers() {
+        let line = Line::new(4, "50 98 2");
+        assert_eq!(line.numbers::<u64>(line.text).unwrap(), vec![50, 98, 2]);
+    }
+
+    #[test]
+    fn test_line_number_error_column() {
+        let line = Line::new(4, "50 98 x3");
+        let err = line.numbers::<u64>(line.text).unwrap_err();
+        assert_eq!(err, ParseError::new(4, 7, "a number", "x3", "50 98 x3"));
+    }
+
+    #[test]
+    fn test_line_split_once() {
+        let line = Line::new(1, "Card 1: 41 | 83");
+        let (label, rest) = line.split_once(line.text, ':').unwrap();
+        assert_eq!((label, rest), ("Card 1", " 41 | 83"));
+
+        let err = line.split_once(label, '|').unwrap_err();
+        assert_eq!(err, ParseError::new(1, 7, "`|`", "", "Card 1: 41 | 83"));
+    }
+
+    #[test]
+    fn test_line_columns_count_characters() {
+        let line = Line::new(1, "½½ x");
+        let err = line.number::<u32>(&line.text[5..]).unwrap_err();
+        assert_eq!(err.column, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a slice of line")]
+    fn test_line_foreign_part() {
+        let text = String::from("abc");
+        let line = Line::new(1, &text);
+        line.error_at("elsewhere", "nothing");
+    }
+}
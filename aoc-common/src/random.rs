@@ -0,0 +1,45 @@
+//! A seeded random number generator for inputs generated by tests and
+//! benchmarks, which must come out the same on every run.
+
+/// A linear congruential generator with the constants Knuth gives for MMIX.
+///
+/// Far from a good source of randomness, but enough to scatter digits and
+/// symbols over a generated puzzle input.
+#[derive(Debug, Clone)]
+pub struct Lcg {
+    state: u64,
+}
+
+impl Lcg {
+    pub fn new(seed: u64) -> Lcg {
+        Lcg { state: seed }
+    }
+
+    /// Returns a number below `bound`, from the high bits of the state as
+    /// the low ones repeat quickly.
+    ///
+    /// Panics if `bound` is 0.
+    pub fn below(&mut self, bound: usize) -> usize {
+        self.state = self
+            .state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.state >> 33) as usize % bound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeatable() {
+        let draw = |seed| {
+            let mut lcg = Lcg::new(seed);
+            (0..20).map(|_| lcg.below(10)).collect::<Vec<usize>>()
+        };
+        assert_eq!(draw(7), draw(7));
+        assert_ne!(draw(7), draw(8));
+        assert!(draw(7).iter().all(|&x| x < 10));
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{Answer, ParseError};
+
+/// A day of Advent of Code, split into a parse phase and two solve phases.
+///
+/// The input is parsed once and both parts solve from the parsed value, so
+/// parsing can be timed, cached and tested on its own.
+pub trait Solution {
+    /// The parsed puzzle input shared by both parts.
+    type Input;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    /// The day of December the puzzle was released, 1 to 25.
+    fn day(&self) -> u8;
+
+    /// Parses the raw puzzle input.
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Solves part 1 of the puzzle.
+    fn part1(&self, input: &Self::Input) -> Self::Answer1;
+
+    /// Solves part 2 of the puzzle.
+    fn part2(&self, input: &Self::Input) -> Self::Answer2;
+}
+
+/// An object safe view of a [`Solution`], so days with different input and
+/// answer types can be registered side by side.
+pub trait Puzzle {
+    fn day(&self) -> u8;
+
+    /// Parses the raw puzzle input, ready to solve either part.
+    fn parse<'a>(&'a self, input: &str) -> Result<Box<dyn Parsed + 'a>, ParseError>;
+}
+
+/// A parsed puzzle input, bound to the solution that parsed it.
+pub trait Parsed {
+    /// Solves the given part, returning `None` for anything but 1 or 2 and
+    /// an error when the input has no answer.
+    fn solve(&self, part: u8) -> Option<Result<String, ParseError>>;
+}
+
+struct Prepared<'a, S: Solution> {
+    solution: &'a S,
+    input: S::Input,
+}
+
+impl<S: Solution> Parsed for Prepared<'_, S> {
+    fn solve(&self, part: u8) -> Option<Result<String, ParseError>> {
+        match part {
+            1 => Some(self.solution.part1(&self.input).render()),
+            2 => Some(self.solution.part2(&self.input).render()),
+            _ => None,
+        }
+    }
+}
+
+impl<S: Solution> Puzzle for S {
+    fn day(&self) -> u8 {
+        Solution::day(self)
+    }
+
+    fn parse<'a>(&'a self, input: &str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        let input = Solution::parse(self, input)?;
+        Ok(Box::new(Prepared {
+            solution: self,
+            input,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::lines;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = Result<String, ParseError>;
+
+        fn day(&self) -> u8 {
+            1
+        }
+        fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+            lines(input).map(|line| line.number(line.text)).collect()
+        }
+        fn part1(&self, input: &Self::Input) -> u32 {
+            input.iter().sum()
+        }
+        fn part2(&self, input: &Self::Input) -> Result<String, ParseError> {
+            match input.len() {
+                0 => Err(ParseError::new(1, 1, "a number", "", "")),
+                len => Ok(format!("{} numbers", len)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_dispatches_by_part() {
+        let puzzle: &dyn Puzzle = &Sum;
+        let parsed = puzzle.parse("1\n2\n3").unwrap();
+        assert_eq!(parsed.solve(1), Some(Ok("6".to_string())));
+        assert_eq!(parsed.solve(2), Some(Ok("3 numbers".to_string())));
+        assert_eq!(parsed.solve(3), None);
+
+        let parsed = puzzle.parse("").unwrap();
+        assert_eq!(parsed.solve(1), Some(Ok("0".to_string())));
+        assert!(matches!(parsed.solve(2), Some(Err(_))));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let puzzle: &dyn Puzzle = &Sum;
+        let err = puzzle.parse("1\ntwo\n3").err().unwrap();
+        assert_eq!(err, ParseError::new(2, 1, "a number", "two", "two"));
+    }
+}
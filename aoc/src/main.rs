@@ -0,0 +1,508 @@
+//! Runs any day and part of the Advent of Code 2023 solutions.
+//!
+//! ```sh
+//! aoc run                                      # every part of every day
+//! aoc run 5                                    # both parts of day 5
+//! aoc run 5 2 --input day-05/input/input.txt   # one part, custom input
+//! aoc brute-force --threads 8                  # day 5 part 2, seed by seed
+//! aoc explain --format json                    # how every day 4 card scores
+//! aoc render --format html > schematic.html    # what day 3 counted
+//! aoc stream --input huge.txt                  # day 3 in constant memory
+//! aoc calibrate --trace                        # the digits of every day 1 line
+//! aoc calibrate --combine all --empty skip     # every digit of a day 1 line
+//! aoc calibrate --vocabulary french.txt        # day 1 digits spelt in French
+//! ```
+
+mod registry;
+mod report;
+
+use aoc_common::input::try_read_input;
+use aoc_common::Solution;
+use clap::{Parser, Subcommand, ValueEnum};
+use day_01::rules::{Combine, Empty, Rules as Calibration};
+use day_01::trace;
+use day_01::vocabulary::Vocabulary;
+use day_03::render;
+use day_03::rules::Rules;
+use day_03::stream::{self, StreamError};
+use day_04::explain;
+use day_05::parallel::{self, Progress};
+use report::Row;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::Instant;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves puzzles and prints their answers and timings
+    Run {
+        /// The day to run, every solved day when omitted
+        day: Option<u8>,
+        /// The part to run, both parts when omitted
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// The puzzle input, defaults to day-NN/input/input.txt
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+    },
+    /// Solves day 5 part 2 by sending every seed through the maps
+    BruteForce {
+        /// The puzzle input, defaults to day-05/input/input.txt
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// The number of worker threads, one per core when omitted
+        #[arg(long, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        threads: Option<usize>,
+    },
+    /// Explains which numbers won on every day 4 card and how it was copied
+    Explain {
+        /// The puzzle input, defaults to day-04/input/input.txt
+        #[arg(long)]
+        input: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Draws the day 3 schematic with its part numbers and gears highlighted
+    Render {
+        /// The puzzle input, defaults to day-03/input/input.txt
+        #[arg(long)]
+        input: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = Image::Ansi)]
+        format: Image,
+    },
+    /// Solves day 1 part 2, or a variant combining the digits of every line
+    /// differently, optionally showing which digits every line was decoded
+    /// from
+    Calibrate {
+        /// The puzzle input, defaults to day-01/input/input.txt
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// The words digits are spelt with, a `<word> <value>` line per word,
+        /// read along with the ASCII digits. English when omitted
+        #[arg(long)]
+        vocabulary: Option<PathBuf>,
+        /// Prints every line with its first and last digit underlined
+        #[arg(long)]
+        trace: bool,
+        /// How the digits of a line make its value: first-last, all, sum, max,
+        /// min or nth=N
+        #[arg(long, default_value = "first-last")]
+        combine: Combine,
+        /// What a line without any digit is worth: error, skip or zero
+        #[arg(long, default_value = "error")]
+        empty: Empty,
+    },
+    /// Solves day 3 reading the schematic a row at a time, for inputs too
+    /// large to hold in memory
+    Stream {
+        /// The puzzle input, defaults to day-03/input/input.txt
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+enum Image {
+    Ansi,
+    Html,
+    Svg,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run { day, part, input } => run(day, part, input),
+        Command::BruteForce { input, threads } => brute_force(input, threads),
+        Command::Explain { input, format } => {
+            let Some(cards) = load(&day_04::Day04, input.clone()) else {
+                return ExitCode::FAILURE;
+            };
+            let explanations = match explain::explain(&cards) {
+                Ok(explanations) => explanations,
+                Err(err) => {
+                    let path = input.unwrap_or_else(|| default_input(4));
+                    eprintln!("{}", err.with_file(&path).render());
+                    return ExitCode::FAILURE;
+                }
+            };
+            match format {
+                Format::Text => print!("{}", explain::render_text(&explanations)),
+                Format::Json => print!("{}", explain::render_json(&explanations)),
+            }
+            ExitCode::SUCCESS
+        }
+        Command::Render { input, format } => {
+            let Some(schematic) = load(&day_03::Day03, input) else {
+                return ExitCode::FAILURE;
+            };
+            let annotation = render::annotate(&schematic, &Rules::default());
+            match format {
+                Image::Ansi => print!("{}", render::render_ansi(&annotation)),
+                Image::Html => print!("{}", render::render_html(&annotation)),
+                Image::Svg => print!("{}", render::render_svg(&annotation)),
+            }
+            ExitCode::SUCCESS
+        }
+        Command::Calibrate {
+            input,
+            vocabulary,
+            trace,
+            combine,
+            empty,
+        } => calibrate(input, vocabulary, trace, Calibration { combine, empty }),
+        Command::Stream { input } => stream(input),
+    }
+}
+
+fn run(day: Option<u8>, part: Option<u8>, input: Option<PathBuf>) -> ExitCode {
+    let solutions = match day {
+        Some(day) => match registry::find(day) {
+            Some(solution) => vec![solution],
+            None => {
+                eprintln!("Day {} is not solved yet.", day);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => registry::solutions(),
+    };
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut rows: Vec<Row> = Vec::new();
+    for solution in solutions {
+        let path = input
+            .clone()
+            .unwrap_or_else(|| default_input(solution.day()));
+
+        let now = Instant::now();
+        let parsed = try_read_input(&path)
+            .map_err(|err| format!("{}: {}", path.display(), err))
+            .and_then(|text| {
+                solution.parse(&text).map_err(|err| {
+                    let err = err.with_file(&path);
+                    eprintln!("{}", err.render());
+                    err.to_string()
+                })
+            });
+        let mut parse = Some(now.elapsed());
+
+        for &part in &parts {
+            let now = Instant::now();
+            let answer = match &parsed {
+                Ok(parsed) => parsed
+                    .solve(part)
+                    .expect("part is validated by the argument parser")
+                    .map_err(|err| {
+                        let err = err.with_file(&path);
+                        eprintln!("{}", err.render());
+                        err.to_string()
+                    }),
+                Err(err) => Err(err.clone()),
+            };
+            rows.push(Row {
+                day: solution.day(),
+                part,
+                answer,
+                parse: if parsed.is_ok() { parse.take() } else { None },
+                solve: now.elapsed(),
+            });
+        }
+    }
+
+    print!("{}", report::render_table(&rows));
+
+    if rows.iter().any(|x| x.answer.is_err()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn brute_force(input: Option<PathBuf>, threads: Option<usize>) -> ExitCode {
+    let Some(almanac) = load(&day_05::Day05, input.clone()) else {
+        return ExitCode::FAILURE;
+    };
+    let threads = threads
+        .or_else(|| thread::available_parallelism().ok().map(|x| x.get()))
+        .unwrap_or(1);
+
+    // Only redraw the progress line when the percentage goes up
+    let shown = AtomicU64::new(0);
+    let now = Instant::now();
+    let location = parallel::brute_force(&almanac, threads, |x: Progress| {
+        let percent = (x.done * 100 / x.total) as u64;
+        if shown.fetch_max(percent, Ordering::Relaxed) < percent {
+            eprint!("\r{:>3}% of {} seeds", percent, x.total);
+        }
+    });
+    eprintln!();
+
+    let location = match location {
+        Ok(location) => location,
+        Err(err) => {
+            let path = input.unwrap_or_else(|| default_input(5));
+            eprintln!("{}", err.with_file(&path).render());
+            return ExitCode::FAILURE;
+        }
+    };
+    println!(
+        "{} on {} threads in {}",
+        location,
+        threads,
+        report::format_duration(now.elapsed())
+    );
+    ExitCode::SUCCESS
+}
+
+fn calibrate(
+    input: Option<PathBuf>,
+    vocabulary: Option<PathBuf>,
+    show: bool,
+    rules: Calibration,
+) -> ExitCode {
+    let Some(document) = load(&day_01::Day01, input.clone()) else {
+        return ExitCode::FAILURE;
+    };
+    let vocabulary = match vocabulary {
+        Some(path) => match load_vocabulary(&path) {
+            Some(vocabulary) => vocabulary,
+            None => return ExitCode::FAILURE,
+        },
+        None => Vocabulary::english(),
+    };
+    for conflict in vocabulary.conflicts() {
+        eprintln!("warning: {}", conflict);
+    }
+    let matcher = vocabulary.with(Vocabulary::digits()).matcher();
+    let traces = trace::trace(&document, &matcher);
+
+    if show {
+        print!("{}", trace::render(&traces, &rules));
+    }
+
+    match rules.sum(&traces) {
+        Ok(sum) => {
+            println!("{}", sum);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            let path = input.unwrap_or_else(|| default_input(1));
+            eprintln!("{}", err.with_file(&path).render());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Reads a vocabulary file for `calibrate`, printing why when it can't.
+fn load_vocabulary(path: &Path) -> Option<Vocabulary> {
+    let text = match try_read_input(path) {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("{}: {}", path.display(), err);
+            return None;
+        }
+    };
+    match Vocabulary::parse(&text) {
+        Ok(vocabulary) => Some(vocabulary),
+        Err(err) => {
+            eprintln!("{}", err.with_file(path).render());
+            None
+        }
+    }
+}
+
+fn stream(input: Option<PathBuf>) -> ExitCode {
+    let path = input.unwrap_or_else(|| default_input(3));
+    let totals = File::open(&path)
+        .map_err(StreamError::Io)
+        .and_then(|file| stream::stream(BufReader::new(file), &Rules::default(), |_| {}));
+
+    match totals {
+        Ok(totals) => {
+            println!("Day 3 part 1: {}", totals.part1);
+            println!("Day 3 part 2: {}", totals.part2);
+            ExitCode::SUCCESS
+        }
+        Err(StreamError::Io(err)) => {
+            eprintln!("{}: {}", path.display(), err);
+            ExitCode::FAILURE
+        }
+        Err(StreamError::Parse(err)) => {
+            eprintln!("{}", err.with_file(&path).render());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Reads and parses the input of a day for the commands specific to it,
+/// printing why when it can't.
+fn load<S: Solution>(solution: &S, input: Option<PathBuf>) -> Option<S::Input> {
+    let path = input.unwrap_or_else(|| default_input(solution.day()));
+    let text = match try_read_input(&path) {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("{}: {}", path.display(), err);
+            return None;
+        }
+    };
+    match solution.parse(&text) {
+        Ok(input) => Some(input),
+        Err(err) => {
+            eprintln!("{}", err.with_file(&path).render());
+            None
+        }
+    }
+}
+
+/// Where a day's personal puzzle input lives, relative to the workspace root.
+fn default_input(day: u8) -> PathBuf {
+    PathBuf::from(format!("day-{:02}/input/input.txt", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_parse_run() {
+        let cli = Cli::try_parse_from(["aoc", "run", "5", "2", "--input", "x.txt"]).unwrap();
+        let Command::Run { day, part, input } = cli.command else {
+            panic!("expected the run command");
+        };
+        assert_eq!(day, Some(5));
+        assert_eq!(part, Some(2));
+        assert_eq!(input, Some(PathBuf::from("x.txt")));
+    }
+
+    #[test]
+    fn test_parse_run_rejects_part_3() {
+        assert!(Cli::try_parse_from(["aoc", "run", "5", "3"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_brute_force() {
+        let cli = Cli::try_parse_from(["aoc", "brute-force", "--threads", "3"]).unwrap();
+        let Command::BruteForce { input, threads } = cli.command else {
+            panic!("expected the brute-force command");
+        };
+        assert_eq!(input, None);
+        assert_eq!(threads, Some(3));
+        assert!(Cli::try_parse_from(["aoc", "brute-force", "--threads", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_explain() {
+        let cli = Cli::try_parse_from(["aoc", "explain", "--format", "json"]).unwrap();
+        let Command::Explain { input, format } = cli.command else {
+            panic!("expected the explain command");
+        };
+        assert_eq!(input, None);
+        assert_eq!(format, Format::Json);
+
+        let cli = Cli::try_parse_from(["aoc", "explain"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Explain {
+                format: Format::Text,
+                ..
+            }
+        ));
+        assert!(Cli::try_parse_from(["aoc", "explain", "--format", "xml"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_render() {
+        let cli = Cli::try_parse_from(["aoc", "render", "--format", "svg"]).unwrap();
+        let Command::Render { input, format } = cli.command else {
+            panic!("expected the render command");
+        };
+        assert_eq!(input, None);
+        assert_eq!(format, Image::Svg);
+
+        let cli = Cli::try_parse_from(["aoc", "render"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Render {
+                format: Image::Ansi,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_calibrate() {
+        let cli = Cli::try_parse_from(["aoc", "calibrate", "--trace"]).unwrap();
+        let Command::Calibrate {
+            input,
+            vocabulary,
+            trace,
+            combine,
+            empty,
+        } = cli.command
+        else {
+            panic!("expected the calibrate command");
+        };
+        assert_eq!((input, vocabulary), (None, None));
+        assert!(trace);
+        assert_eq!((combine, empty), (Combine::FirstLast, Empty::Error));
+
+        let cli =
+            Cli::try_parse_from(["aoc", "calibrate", "--combine", "nth=2", "--empty", "zero"])
+                .unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Calibrate {
+                trace: false,
+                combine: Combine::Nth(2),
+                empty: Empty::Zero,
+                ..
+            }
+        ));
+        assert!(Cli::try_parse_from(["aoc", "calibrate", "--combine", "nth=0"]).is_err());
+
+        let cli = Cli::try_parse_from(["aoc", "calibrate", "--vocabulary", "fr.txt"]).unwrap();
+        let Command::Calibrate { vocabulary, .. } = cli.command else {
+            panic!("expected the calibrate command");
+        };
+        assert_eq!(vocabulary, Some(PathBuf::from("fr.txt")));
+    }
+
+    #[test]
+    fn test_parse_stream() {
+        let cli = Cli::try_parse_from(["aoc", "stream", "--input", "x.txt"]).unwrap();
+        let Command::Stream { input } = cli.command else {
+            panic!("expected the stream command");
+        };
+        assert_eq!(input, Some(PathBuf::from("x.txt")));
+    }
+
+    #[test]
+    fn test_default_input() {
+        assert_eq!(default_input(5), PathBuf::from("day-05/input/input.txt"));
+    }
+}
@@ -0,0 +1,58 @@
+use aoc_common::Puzzle;
+
+/// Every solved day, in order.
+pub fn solutions() -> Vec<Box<dyn Puzzle>> {
+    vec![
+        Box::new(day_01::Day01),
+        Box::new(day_02::Day02),
+        Box::new(day_03::Day03),
+        Box::new(day_04::Day04),
+        Box::new(day_05::Day05),
+    ]
+}
+
+/// Returns the solution registered for a day, if there is one.
+pub fn find(day: u8) -> Option<Box<dyn Puzzle>> {
+    solutions().into_iter().find(|x| x.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::read_input;
+
+    #[test]
+    fn test_days_are_unique_and_ordered() {
+        let days: Vec<u8> = solutions().iter().map(|x| x.day()).collect();
+        assert_eq!(days, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(3).unwrap().day(), 3);
+        assert!(find(25).is_none());
+    }
+
+    #[test]
+    fn test_examples() {
+        let examples = [
+            (1, 1, "../day-01/input/example1.txt", "142"),
+            (1, 2, "../day-01/input/example2.txt", "281"),
+            (2, 1, "../day-02/input/example1.txt", "8"),
+            (2, 2, "../day-02/input/example1.txt", "2286"),
+            (3, 1, "../day-03/input/example1.txt", "4361"),
+            (3, 2, "../day-03/input/example1.txt", "467835"),
+            (4, 1, "../day-04/input/example1.txt", "13"),
+            (4, 2, "../day-04/input/example1.txt", "30"),
+            (5, 1, "../day-05/input/example.txt", "35"),
+            (5, 2, "../day-05/input/example.txt", "46"),
+        ];
+
+        for (day, part, path, expected) in examples {
+            let solution = find(day).unwrap();
+            let parsed = solution.parse(&read_input(path)).unwrap();
+            let answer = parsed.solve(part).unwrap().unwrap();
+            assert_eq!(answer, expected, "day {} part {}", day, part);
+        }
+    }
+}
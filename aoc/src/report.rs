@@ -0,0 +1,105 @@
+use std::time::Duration;
+
+/// The outcome of running one part of one day.
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Result<String, String>,
+    /// Time spent parsing the input, only set on the first part of a day as
+    /// the parsed input is shared by both parts.
+    pub parse: Option<Duration>,
+    /// Time spent solving the part from the parsed input.
+    pub solve: Duration,
+}
+
+/// Renders the rows as an aligned table with a header line.
+pub fn render_table(rows: &[Row]) -> String {
+    let answers: Vec<String> = rows
+        .iter()
+        .map(|x| match &x.answer {
+            Ok(answer) => answer.clone(),
+            Err(err) => format!("error: {}", err),
+        })
+        .collect();
+    let width = answers
+        .iter()
+        .map(|x| x.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    let mut table = format!(
+        "Day  Part  {:<width$}  {:>8}  {:>8}\n",
+        "Answer", "Parse", "Solve"
+    );
+    for (row, answer) in rows.iter().zip(answers) {
+        let parse = row.parse.map(format_duration).unwrap_or_default();
+        let solve = match row.answer {
+            Ok(_) => format_duration(row.solve),
+            Err(_) => "-".to_string(),
+        };
+        table.push_str(&format!(
+            "{:>3}  {:>4}  {:<width$}  {:>8}  {:>8}\n",
+            row.day, row.part, answer, parse, solve
+        ));
+    }
+    table
+}
+
+pub fn format_duration(elapsed: Duration) -> String {
+    let micros = elapsed.as_micros();
+    if micros < 1_000 {
+        format!("{}µs", micros)
+    } else if micros < 1_000_000 {
+        format!("{:.2}ms", micros as f64 / 1_000.0)
+    } else {
+        format!("{:.2}s", elapsed.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_micros(250)), "250µs");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_millis(2_250)), "2.25s");
+    }
+
+    #[test]
+    fn test_render_table() {
+        let rows = vec![
+            Row {
+                day: 1,
+                part: 1,
+                answer: Ok("142".to_string()),
+                parse: Some(Duration::from_micros(3)),
+                solve: Duration::from_micros(12),
+            },
+            Row {
+                day: 1,
+                part: 2,
+                answer: Ok("281".to_string()),
+                parse: None,
+                solve: Duration::from_micros(1_500),
+            },
+            Row {
+                day: 5,
+                part: 1,
+                answer: Err("missing input".to_string()),
+                parse: None,
+                solve: Duration::ZERO,
+            },
+        ];
+
+        let expected = "\
+Day  Part  Answer                   Parse     Solve
+  1     1  142                        3µs      12µs
+  1     2  281                               1.50ms
+  5     1  error: missing input                   -
+";
+        assert_eq!(render_table(&rows), expected);
+    }
+}
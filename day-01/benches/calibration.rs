@@ -0,0 +1,219 @@
+//! Compares the calibration decoders working on `&str`, through the digit
+//! vocabularies, against the byte level fast path on a large generated
+//! calibration document, and all of them against the original decoders that
+//! went through `format!` and `parse`.
+//!
+//! The original part 2 decoder is far too slow for the whole document, so the
+//! original decoders run on its first `ORIGINAL_SIZE` bytes, which criterion
+//! reports as their own throughput.
+//!
+//! ```sh
+//! cargo bench -p day-01
+//! ```
+
+use aoc_common::random::Lcg;
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use day_01::{fast, part1, part2};
+
+/// The size of the generated document, in bytes.
+const SIZE: usize = 100 << 20;
+
+/// How much of the document the original decoders run on, in bytes.
+const ORIGINAL_SIZE: usize = 1 << 20;
+
+/// The decoders as first written, with only their sums widened to `u64` so
+/// they do not overflow on the generated document.
+mod original {
+    use lazy_static::lazy_static;
+    use std::collections::HashMap;
+
+    pub fn part1(input: &str) -> u64 {
+        let mut values: Vec<u32> = Vec::new();
+
+        for line in input.lines() {
+            let mut left = 0;
+            let mut right = 0;
+
+            for char in line.chars() {
+                if char.is_numeric() {
+                    left = char.to_digit(10).unwrap();
+                    break;
+                }
+            }
+            for char in line.chars().rev() {
+                if char.is_numeric() {
+                    right = char.to_digit(10).unwrap();
+                    break;
+                }
+            }
+
+            let result = format!("{}{}", left, right).parse::<u32>().unwrap();
+            values.push(result);
+        }
+        values.iter().map(|&x| u64::from(x)).sum()
+    }
+
+    pub fn part2(input: &str) -> u64 {
+        let mut sums: Vec<u32> = Vec::new();
+
+        for line in input.lines() {
+            sums.push(parse_line(line));
+        }
+
+        sums.iter().map(|&x| u64::from(x)).sum()
+    }
+
+    lazy_static! {
+        static ref NUMBERS: HashMap<&'static str, u32> = {
+            let mut m = HashMap::new();
+            m.insert("zero", 0);
+            m.insert("one", 1);
+            m.insert("two", 2);
+            m.insert("three", 3);
+            m.insert("four", 4);
+            m.insert("five", 5);
+            m.insert("six", 6);
+            m.insert("seven", 7);
+            m.insert("eight", 8);
+            m.insert("nine", 9);
+            m.insert("0", 0);
+            m.insert("1", 1);
+            m.insert("2", 2);
+            m.insert("3", 3);
+            m.insert("4", 4);
+            m.insert("5", 5);
+            m.insert("6", 6);
+            m.insert("7", 7);
+            m.insert("8", 8);
+            m.insert("9", 9);
+            m
+        };
+    }
+
+    struct Anchor {
+        value: String,
+        target: String,
+    }
+
+    impl Anchor {
+        fn new(value: String, target: String) -> Anchor {
+            Anchor { value, target }
+        }
+    }
+
+    fn parse_line(line: &str) -> u32 {
+        let mut values: Vec<u32> = Vec::new();
+        let mut anchors: Vec<Anchor> = Vec::new();
+
+        for char in line.chars() {
+            for anchor in anchors.iter_mut() {
+                anchor.value.push(char);
+            }
+
+            for key in NUMBERS.keys() {
+                if key.starts_with(char) {
+                    anchors.push(Anchor::new(char.to_string(), key.to_string()));
+                }
+
+                let mut to_remove: Vec<usize> = Vec::new();
+
+                for (idx, anchor) in anchors.iter().enumerate() {
+                    if anchor.value == anchor.target {
+                        values.push(NUMBERS.get(&anchor.value.as_str()).unwrap().to_owned());
+                        to_remove.push(idx)
+                    } else if !anchor.target.starts_with(&anchor.value) {
+                        to_remove.push(idx)
+                    }
+                }
+
+                for i in to_remove.iter().rev() {
+                    anchors.remove(*i);
+                }
+            }
+        }
+
+        let first = values[0];
+        let last = values[values.len() - 1];
+
+        let combined = format!("{}{}", first, last);
+
+        combined.parse::<u32>().unwrap()
+    }
+}
+
+/// Generates lines of letters sprinkled with digits and digit words, every
+/// line with at least one digit so both parts can solve it, from a fixed
+/// seed so runs compare.
+fn generate(size: usize) -> String {
+    const WORDS: [&str; 10] = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    let mut lcg = Lcg::new(0x2023_1201);
+
+    let mut document = String::with_capacity(size + 64);
+    while document.len() < size {
+        let digit = lcg.below(10);
+        let length = 8 + lcg.below(40);
+        let at = lcg.below(length);
+        for i in 0..length {
+            match lcg.below(16) {
+                _ if i == at => document.push(char::from(b'0' + digit as u8)),
+                0 => document.push_str(WORDS[lcg.below(10)]),
+                1 => document.push(char::from(b'0' + lcg.below(10) as u8)),
+                _ => document.push(char::from(b'a' + lcg.below(26) as u8)),
+            }
+        }
+        document.push('\n');
+    }
+    document
+}
+
+fn bench_calibration(c: &mut Criterion) {
+    let document = generate(SIZE);
+    assert_eq!(
+        fast::part1(document.as_bytes()),
+        part1::part1(&document),
+        "both decoders should agree"
+    );
+    assert_eq!(fast::part2(document.as_bytes()), part2::part2(&document));
+
+    let end = document[..ORIGINAL_SIZE].rfind('\n').map_or(0, |x| x + 1);
+    let prefix = &document[..end];
+    assert_eq!(Ok(original::part1(prefix)), part1::part1(prefix));
+    assert_eq!(Ok(original::part2(prefix)), part2::part2(prefix));
+
+    let mut group = c.benchmark_group("calibration/original");
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(prefix.len() as u64));
+
+    group.bench_function("part1/format", |b| {
+        b.iter(|| original::part1(black_box(prefix)))
+    });
+    group.bench_function("part2/anchors", |b| {
+        b.iter(|| original::part2(black_box(prefix)))
+    });
+
+    group.finish();
+
+    let mut group = c.benchmark_group("calibration");
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(document.len() as u64));
+
+    group.bench_function("part1/str", |b| {
+        b.iter(|| part1::part1(black_box(&document)))
+    });
+    group.bench_function("part1/bytes", |b| {
+        b.iter(|| fast::part1(black_box(document.as_bytes())))
+    });
+    group.bench_function("part2/str", |b| {
+        b.iter(|| part2::part2(black_box(&document)))
+    });
+    group.bench_function("part2/bytes", |b| {
+        b.iter(|| fast::part2(black_box(document.as_bytes())))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_calibration);
+criterion_main!(benches);
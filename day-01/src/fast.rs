@@ -0,0 +1,150 @@
+use aoc_common::parse::Line;
+use aoc_common::ParseError;
+use lazy_static::lazy_static;
+use memchr::memchr_iter;
+use memchr::memmem::{Finder, FinderRev};
+
+/// The English digit words, indexed by their value.
+const WORDS: [&[u8]; 10] = [
+    b"zero", b"one", b"two", b"three", b"four", b"five", b"six", b"seven", b"eight", b"nine",
+];
+
+/// Iterates over the lines of `input` with their 1-based number, without
+/// their `\n` or `\r\n`, like `str::lines`.
+fn lines(input: &[u8]) -> impl Iterator<Item = (usize, &[u8])> {
+    let mut start = 0;
+    let ends = memchr_iter(b'\n', input).chain(std::iter::once(input.len()));
+    ends.filter_map(move |end| {
+        let line = &input[start..end];
+        start = end + 1;
+        (end < input.len() || !line.is_empty()).then_some(line)
+    })
+    .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+    .enumerate()
+    .map(|(idx, line)| (idx + 1, line))
+}
+
+/// The error for a line without any digit, the only time anything allocates.
+fn no_digit(number: usize, line: &[u8]) -> ParseError {
+    let text = String::from_utf8_lossy(line);
+    Line::new(number, &text).error("a line with a digit")
+}
+
+lazy_static! {
+    /// A forward searcher for every English digit word, indexed by its value.
+    static ref FORWARD: Vec<Finder<'static>> = WORDS.iter().map(Finder::new).collect();
+    /// A backward searcher for every English digit word, indexed by its value.
+    static ref BACKWARD: Vec<FinderRev<'static>> =
+        WORDS.iter().map(FinderRev::new).collect();
+}
+
+/// Returns the value of the English digit word starting first in `haystack`.
+fn first_word(haystack: &[u8]) -> Option<u64> {
+    FORWARD
+        .iter()
+        .zip(0..)
+        .filter_map(|(finder, value)| Some((finder.find(haystack)?, value)))
+        .min()
+        .map(|(_, value)| value)
+}
+
+/// Returns the value of the English digit word starting last in `haystack`.
+fn last_word(haystack: &[u8]) -> Option<u64> {
+    BACKWARD
+        .iter()
+        .zip(0..)
+        .filter_map(|(finder, value)| Some((finder.rfind(haystack)?, value)))
+        .max()
+        .map(|(_, value)| value)
+}
+
+/// Solves part 1 straight from the bytes of the input, scanning every line
+/// from both ends for its first and last ASCII digit.
+///
+/// Fails on the first line without a digit, as `part1::part1` does.
+pub fn part1(input: &[u8]) -> Result<u64, ParseError> {
+    let mut sum = 0;
+    for (number, line) in lines(input) {
+        let Some(first) = line.iter().position(|x| x.is_ascii_digit()) else {
+            return Err(no_digit(number, line));
+        };
+        let last = line.iter().rposition(|x| x.is_ascii_digit()).unwrap();
+        sum += u64::from(line[first] - b'0') * 10 + u64::from(line[last] - b'0');
+    }
+    Ok(sum)
+}
+
+/// Solves part 2 straight from the bytes of the input. The first and last
+/// ASCII digit of every line split it, and `memmem` only looks for English
+/// digit words before the first and after the last.
+///
+/// No English digit word is found inside another, so the word starting last
+/// is also the one ending last, and overlapping words such as `oneight` are
+/// read as both.
+pub fn part2(input: &[u8]) -> Result<u64, ParseError> {
+    let mut sum = 0;
+    for (number, line) in lines(input) {
+        let digit = |i: usize| u64::from(line[i] - b'0');
+        let first_digit = line.iter().position(|x| x.is_ascii_digit());
+        let last_digit = line.iter().rposition(|x| x.is_ascii_digit());
+
+        let before = &line[..first_digit.unwrap_or(line.len())];
+        let Some(first) = first_word(before).or(first_digit.map(digit)) else {
+            return Err(no_digit(number, line));
+        };
+        let after = &line[last_digit.map_or(0, |x| x + 1)..];
+        let last = last_word(after).or(last_digit.map(digit)).unwrap();
+        sum += first * 10 + last;
+    }
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::read_input;
+
+    #[test]
+    fn test_lines() {
+        let found: Vec<(usize, &[u8])> = lines(b"ab\r\n\ncd\n").collect();
+        assert_eq!(found, vec![(1, &b"ab"[..]), (2, &b""[..]), (3, &b"cd"[..])]);
+        assert_eq!(lines(b"ab").count(), 1);
+        assert_eq!(lines(b"").count(), 0);
+    }
+
+    #[test]
+    fn test_examples() {
+        let example1 = read_input("input/example1.txt");
+        assert_eq!(part1(example1.as_bytes()), Ok(142));
+        assert_eq!(part1(example1.as_bytes()), crate::part1::part1(&example1));
+
+        let example2 = read_input("input/example2.txt");
+        assert_eq!(part2(example2.as_bytes()), Ok(281));
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        for (line, expected) in [
+            ("oneight", 18),
+            ("eighthree", 83),
+            ("threetwoonez1gtrd", 31),
+            ("769twotwo6rv9", 79),
+            ("zeronine", 9),
+            ("ssevenn", 77),
+        ] {
+            assert_eq!(part2(line.as_bytes()), Ok(expected), "{}", line);
+            assert_eq!(crate::part2::part2(line), Ok(expected), "{}", line);
+        }
+    }
+
+    #[test]
+    fn test_no_digit() {
+        let err = part1(b"1abc2\npqrstu\n").unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (2, "pqrstu"));
+        assert_eq!(err, crate::part1::part1("1abc2\npqrstu\n").unwrap_err());
+
+        assert_eq!(part2(b"two\nxyz"), crate::part2::part2("two\nxyz"));
+        let err = part2(b"two\n\xffxyz").unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (2, "\u{FFFD}xyz"));
+    }
+}
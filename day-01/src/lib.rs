@@ -0,0 +1,37 @@
+//! https://adventofcode.com/2023/day/1
+
+use aoc_common::{ParseError, Solution};
+
+pub mod fast;
+pub mod matcher;
+pub mod part1;
+pub mod part2;
+pub mod rules;
+pub mod trace;
+pub mod vocabulary;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    /// Both parts decode the calibration document differently, so the parsed
+    /// input is the document itself.
+    type Input = String;
+    type Answer1 = Result<u64, ParseError>;
+    type Answer2 = Result<u64, ParseError>;
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<u64, ParseError> {
+        part1::part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<u64, ParseError> {
+        part2::part2(input)
+    }
+}
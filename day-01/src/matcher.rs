@@ -0,0 +1,176 @@
+use std::collections::{BTreeMap, VecDeque};
+
+/// A word found in a line, `start..end` being its byte range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+/// A state of the automaton, reached after reading some prefix of a word.
+#[derive(Debug, Default)]
+struct Node {
+    next: BTreeMap<char, usize>,
+    /// The state of the longest proper suffix of this state's prefix that is
+    /// also the prefix of a word.
+    fail: usize,
+    /// The words ending at this state, suffixes included, as their length in
+    /// bytes and value.
+    words: Vec<(usize, u32)>,
+}
+
+/// Finds every occurrence of a set of words in a single pass over a line,
+/// overlapping occurrences included, with an Aho-Corasick automaton.
+#[derive(Debug)]
+pub struct Matcher {
+    nodes: Vec<Node>,
+}
+
+impl Matcher {
+    /// Builds the automaton matching every word to its value.
+    ///
+    /// Panics if a word is empty.
+    pub fn new<'a, I: IntoIterator<Item = (&'a str, u32)>>(words: I) -> Matcher {
+        let mut nodes: Vec<Node> = vec![Node::default()];
+
+        for (word, value) in words {
+            assert!(!word.is_empty(), "cannot match an empty word");
+            let mut state = 0;
+            for c in word.chars() {
+                state = match nodes[state].next.get(&c) {
+                    Some(&next) => next,
+                    None => {
+                        nodes.push(Node::default());
+                        let next = nodes.len() - 1;
+                        nodes[state].next.insert(c, next);
+                        next
+                    }
+                };
+            }
+            nodes[state].words.push((word.len(), value));
+        }
+
+        // Breadth first, the failure of every shallower state is known when
+        // working out the failure of a state
+        let mut queue: VecDeque<usize> = nodes[0].next.values().copied().collect();
+        while let Some(state) = queue.pop_front() {
+            let next: Vec<(char, usize)> =
+                nodes[state].next.iter().map(|(c, x)| (*c, *x)).collect();
+            for (c, child) in next {
+                let mut fail = nodes[state].fail;
+                let fail = loop {
+                    if let Some(&target) = nodes[fail].next.get(&c) {
+                        break target;
+                    }
+                    if fail == 0 {
+                        break 0;
+                    }
+                    fail = nodes[fail].fail;
+                };
+                nodes[child].fail = fail;
+                let inherited = nodes[fail].words.clone();
+                nodes[child].words.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+
+        Matcher { nodes }
+    }
+
+    /// Returns every occurrence of the words in `text`, ordered by where they
+    /// start, the shorter first when two start at the same place.
+    pub fn find_all(&self, text: &str) -> Vec<Match> {
+        let mut matches: Vec<Match> = Vec::new();
+        let mut state = 0;
+
+        for (offset, c) in text.char_indices() {
+            state = loop {
+                if let Some(&next) = self.nodes[state].next.get(&c) {
+                    break next;
+                }
+                if state == 0 {
+                    break 0;
+                }
+                state = self.nodes[state].fail;
+            };
+
+            let end = offset + c.len_utf8();
+            for &(len, value) in &self.nodes[state].words {
+                matches.push(Match {
+                    start: end - len,
+                    end,
+                    value,
+                });
+            }
+        }
+
+        matches.sort_by_key(|x| (x.start, x.end));
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matcher() -> Matcher {
+        Matcher::new([("one", 1), ("eight", 8), ("three", 3), ("two", 2), ("1", 1)])
+    }
+
+    #[test]
+    fn test_find_all() {
+        let matches = matcher().find_all("xtwone1");
+        assert_eq!(
+            matches,
+            vec![
+                Match {
+                    start: 1,
+                    end: 4,
+                    value: 2
+                },
+                Match {
+                    start: 3,
+                    end: 6,
+                    value: 1
+                },
+                Match {
+                    start: 6,
+                    end: 7,
+                    value: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let values: Vec<u32> = matcher()
+            .find_all("oneighthree")
+            .iter()
+            .map(|x| x.value)
+            .collect();
+        assert_eq!(values, vec![1, 8, 3]);
+    }
+
+    #[test]
+    fn test_suffix_words() {
+        // `ne` is a word inside `one`, found through the failure links
+        let matcher = Matcher::new([("one", 1), ("ne", 9)]);
+        let matches = matcher.find_all("one");
+        assert_eq!(matches.len(), 2);
+        assert_eq!((matches[1].start, matches[1].value), (1, 9));
+    }
+
+    #[test]
+    fn test_byte_offsets() {
+        let matches = matcher().find_all("été-one");
+        assert_eq!((matches[0].start, matches[0].end), (6, 9));
+    }
+
+    #[test]
+    #[should_panic(expected = "empty word")]
+    fn test_empty_word() {
+        Matcher::new([("", 0)]);
+    }
+}
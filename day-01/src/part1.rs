@@ -0,0 +1,105 @@
+use crate::vocabulary::unicode_digit;
+use aoc_common::parse::lines;
+#[cfg(test)]
+use aoc_common::read_input;
+use aoc_common::ParseError;
+
+#[cfg(test)]
+const EXAMPLE1: &str = "input/example1.txt";
+#[cfg(test)]
+const INPUT: &str = "input/input.txt";
+
+/// Which characters are read as digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Digits {
+    /// `0` to `9` only.
+    Ascii,
+    /// The decimal digits of every script in `UNICODE_ZEROS`, eg `٣` for 3.
+    Unicode,
+}
+
+impl Digits {
+    /// Returns the value of `c` if it is a digit. Characters that are numeric
+    /// without being decimal digits, such as `½` or `三`, never are.
+    pub fn value(self, c: char) -> Option<u32> {
+        match self {
+            Digits::Ascii => c.to_digit(10),
+            Digits::Unicode => unicode_digit(c),
+        }
+    }
+}
+
+/// Sums the calibration value of every line, made of its first and last digit.
+///
+/// Fails on the first line without a digit.
+pub fn decode(input: &str, digits: Digits) -> Result<u64, ParseError> {
+    let mut sum = 0;
+
+    for line in lines(input) {
+        let mut chars = line.text.chars();
+        let Some(left) = chars.by_ref().find_map(|x| digits.value(x)) else {
+            return Err(line.error("a line with a digit"));
+        };
+        let right = chars.rev().find_map(|x| digits.value(x)).unwrap_or(left);
+
+        sum += u64::from(left) * 10 + u64::from(right);
+    }
+    Ok(sum)
+}
+
+pub fn part1(input: &str) -> Result<u64, ParseError> {
+    decode(input, Digits::Ascii)
+}
+
+#[cfg(test)]
+fn test1() -> Result<u64, ParseError> {
+    let input = read_input(EXAMPLE1);
+    part1(&input)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn it_works() {
+        use super::*;
+        let result = test1();
+        assert_eq!(result, Ok(142));
+    }
+
+    #[test]
+    fn unicode_digits() {
+        use super::*;
+        assert_eq!(decode("a٣b٧c\n5x", Digits::Unicode), Ok(37 + 55));
+        assert_eq!(decode("a٣b7c", Digits::Ascii), Ok(77));
+    }
+
+    #[test]
+    fn numeric_characters_are_not_digits() {
+        use super::*;
+        assert_eq!(decode("½x3三", Digits::Ascii), Ok(33));
+        assert_eq!(decode("½x3三", Digits::Unicode), Ok(33));
+    }
+
+    #[test]
+    fn line_without_digits() {
+        use super::*;
+        let err = decode("1abc2\npqr½stu\na1b2", Digits::Ascii).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "a line with a digit");
+
+        let err = decode("1\n\n2", Digits::Ascii).unwrap_err();
+        assert_eq!(err.line, 2);
+    }
+}
+
+#[cfg(test)]
+mod tests_result {
+    #[test]
+    #[ignore = "requires personal puzzle input"]
+    fn part1_true_result() {
+        use super::*;
+        let input = read_input(INPUT);
+        let result = part1(&input);
+        assert_eq!(result, Ok(53194));
+    }
+}
@@ -0,0 +1,113 @@
+use crate::matcher::Matcher;
+use crate::rules::{Combine, Rules};
+use crate::trace::{trace, trace_line};
+use crate::vocabulary::Vocabulary;
+#[cfg(test)]
+use aoc_common::read_input;
+use aoc_common::ParseError;
+use lazy_static::lazy_static;
+
+#[cfg(test)]
+const EXAMPLE2: &str = "input/example2.txt";
+#[cfg(test)]
+const INPUT: &str = "input/input.txt";
+
+/// Fails on the first line without a digit.
+pub fn part2(input: &str) -> Result<u64, ParseError> {
+    Rules::default().sum(&trace(input, &MATCHER))
+}
+
+lazy_static! {
+    /// English words and ASCII digits, as the puzzle spells calibration values.
+    static ref MATCHER: Matcher = Vocabulary::english().with(Vocabulary::digits()).matcher();
+}
+
+#[cfg(test)]
+fn parse_line(line: &str) -> Result<u64, String> {
+    decode_line(line, &MATCHER)
+}
+
+/// Combines the first and last digit `matcher` finds in `line`, failing with
+/// what the line lacked. `rules::Rules` combines them in other ways.
+pub fn decode_line(line: &str, matcher: &Matcher) -> Result<u64, String> {
+    Combine::FirstLast.apply(&trace_line(line, matcher))
+}
+
+#[cfg(test)]
+mod test_part2 {
+    #[test]
+    fn example_input() {
+        use super::*;
+        let result = part2(&read_input(EXAMPLE2));
+        assert_eq!(result, Ok(281));
+    }
+
+    #[test]
+    fn edge_cases_oneeight() {
+        use super::*;
+        let result = parse_line("oneight");
+        assert_eq!(result, Ok(18));
+    }
+
+    #[test]
+    fn edge_cases_two1nine() {
+        use super::*;
+        let result = parse_line("two1nine");
+        assert_eq!(result, Ok(29));
+    }
+
+    #[test]
+    fn edge_cases_eighthree() {
+        use super::*;
+        let result = parse_line("eighthree");
+        assert_eq!(result, Ok(83));
+    }
+
+    #[test]
+    fn edge_cases_sevenine() {
+        use super::*;
+        let result = parse_line("sevenine");
+        assert_eq!(result, Ok(79));
+    }
+
+    #[test]
+    fn edge_cases_threetwoonez1gtrd() {
+        use super::*;
+        let result = parse_line("threetwoonez1gtrd");
+        assert_eq!(result, Ok(31));
+    }
+
+    #[test]
+    fn edge_cases_769twotwo6rv9() {
+        use super::*;
+        let result = parse_line("769twotwo6rv9");
+        assert_eq!(result, Ok(79));
+    }
+
+    #[test]
+    fn edge_cases_oneighthree() {
+        use super::*;
+        let result = parse_line("xoneighthreex");
+        assert_eq!(result, Ok(13));
+    }
+
+    #[test]
+    fn no_digit() {
+        use super::*;
+        assert_eq!(parse_line("abc"), Err("a line with a digit".to_string()));
+        let err = part2("two1\nabc\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.expected.as_str()),
+            (2, "a line with a digit")
+        );
+    }
+
+    #[test]
+    #[ignore = "requires personal puzzle input"]
+    fn test_correct_answer() {
+        use super::*;
+        let correct_answer = 54249;
+        let answer = part2(&read_input(INPUT));
+        assert_eq!(answer, Ok(correct_answer));
+    }
+}
@@ -0,0 +1,276 @@
+use crate::trace::{first_last, LineTrace, Token};
+use aoc_common::parse::Line;
+use aoc_common::ParseError;
+use std::str::FromStr;
+
+/// How the digits found in a line make its calibration value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combine {
+    /// The first digit followed by the one ending last, as the puzzle does.
+    FirstLast,
+    /// Every digit in turn, making a number as long as the line has digits.
+    /// Overlapping words such as `oneight` each count.
+    All,
+    /// The sum of every digit.
+    Sum,
+    /// The largest digit.
+    Max,
+    /// The smallest digit.
+    Min,
+    /// The nth digit, counting from 1.
+    Nth(usize),
+}
+
+/// What becomes of a line without any digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Empty {
+    /// The document is rejected.
+    Error,
+    /// The line has no value and adds nothing.
+    Skip,
+    /// The line is worth 0.
+    Zero,
+}
+
+/// How a calibration document is decoded from the digits of its lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    pub combine: Combine,
+    pub empty: Empty,
+}
+
+impl Default for Rules {
+    /// The rules of the puzzle: the first and last digit, and every line has
+    /// one.
+    fn default() -> Rules {
+        Rules {
+            combine: Combine::FirstLast,
+            empty: Empty::Error,
+        }
+    }
+}
+
+/// Writes every value in turn, eg 10 then 11 as 1011.
+fn concatenate<I: IntoIterator<Item = u64>>(values: I) -> Result<Option<u64>, String> {
+    let mut number: Option<u64> = None;
+    for value in values {
+        let shift = 10u64.checked_pow(value.checked_ilog10().unwrap_or(0) + 1);
+        let concatenated = shift
+            .and_then(|x| number.unwrap_or(0).checked_mul(x))
+            .and_then(|x| x.checked_add(value))
+            .ok_or("a value within 64 bits")?;
+        number = Some(concatenated);
+    }
+    Ok(number)
+}
+
+impl Combine {
+    /// Combines the values of `tokens`, failing with what the line lacked.
+    pub fn apply(self, tokens: &[Token]) -> Result<u64, String> {
+        let values = tokens.iter().map(|x| u64::from(x.value));
+        let value = match self {
+            Combine::FirstLast => match first_last(tokens) {
+                Some((first, last)) => {
+                    concatenate([u64::from(first.value), u64::from(last.value)])?
+                }
+                None => None,
+            },
+            Combine::All => concatenate(values)?,
+            Combine::Sum => (!tokens.is_empty()).then(|| values.sum()),
+            Combine::Max => values.max(),
+            Combine::Min => values.min(),
+            Combine::Nth(_) if tokens.is_empty() => None,
+            Combine::Nth(n) => {
+                return n
+                    .checked_sub(1)
+                    .and_then(|x| tokens.get(x))
+                    .map(|x| u64::from(x.value))
+                    .ok_or_else(|| format!("a line with at least {} digits", n));
+            }
+        };
+        value.ok_or_else(|| "a line with a digit".to_string())
+    }
+}
+
+impl Rules {
+    /// The calibration value of a traced line, `None` when it is skipped.
+    pub fn value(&self, trace: &LineTrace) -> Result<Option<u64>, ParseError> {
+        if trace.tokens.is_empty() {
+            match self.empty {
+                Empty::Error => {}
+                Empty::Skip => return Ok(None),
+                Empty::Zero => return Ok(Some(0)),
+            }
+        }
+        let line = Line::new(trace.number, trace.text);
+        self.combine
+            .apply(&trace.tokens)
+            .map(Some)
+            .map_err(|x| line.error(x))
+    }
+
+    /// Sums the calibration value of every traced line.
+    ///
+    /// Fails on the first line without a value, or when the sum overflows.
+    pub fn sum(&self, traces: &[LineTrace]) -> Result<u64, ParseError> {
+        let mut sum: u64 = 0;
+        for trace in traces {
+            let value = self.value(trace)?.unwrap_or(0);
+            sum = sum
+                .checked_add(value)
+                .ok_or_else(|| Line::new(trace.number, trace.text).error("a sum within 64 bits"))?;
+        }
+        Ok(sum)
+    }
+}
+
+impl FromStr for Combine {
+    type Err = String;
+
+    /// Parses `first-last`, `all`, `sum`, `max`, `min` or `nth=N`.
+    fn from_str(s: &str) -> Result<Combine, String> {
+        let combine = match s {
+            "first-last" => Combine::FirstLast,
+            "all" => Combine::All,
+            "sum" => Combine::Sum,
+            "max" => Combine::Max,
+            "min" => Combine::Min,
+            _ => match s.strip_prefix("nth=").map(str::parse) {
+                Some(Ok(n)) if n > 0 => Combine::Nth(n),
+                Some(_) => return Err(format!("expected nth=N with N from 1, found {:?}", s)),
+                None => {
+                    return Err(format!(
+                        "expected first-last, all, sum, max, min or nth=N, found {:?}",
+                        s
+                    ))
+                }
+            },
+        };
+        Ok(combine)
+    }
+}
+
+impl FromStr for Empty {
+    type Err = String;
+
+    /// Parses `error`, `skip` or `zero`.
+    fn from_str(s: &str) -> Result<Empty, String> {
+        match s {
+            "error" => Ok(Empty::Error),
+            "skip" => Ok(Empty::Skip),
+            "zero" => Ok(Empty::Zero),
+            _ => Err(format!("expected error, skip or zero, found {:?}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace::trace;
+    use crate::vocabulary::Vocabulary;
+    use aoc_common::read_input;
+
+    fn values(input: &str, rules: Rules) -> Vec<Result<Option<u64>, String>> {
+        let matcher = Vocabulary::english().with(Vocabulary::digits()).matcher();
+        trace(input, &matcher)
+            .iter()
+            .map(|x| rules.value(x).map_err(|err| err.expected))
+            .collect()
+    }
+
+    fn combine(combine: Combine) -> Rules {
+        Rules {
+            combine,
+            ..Rules::default()
+        }
+    }
+
+    #[test]
+    fn test_default() {
+        let matcher = Vocabulary::english().with(Vocabulary::digits()).matcher();
+        let input = read_input("input/example2.txt");
+        assert_eq!(Rules::default().sum(&trace(&input, &matcher)), Ok(281));
+    }
+
+    #[test]
+    fn test_combine() {
+        let line = "x7oneight2";
+        for (rules, expected) in [
+            (Combine::FirstLast, 72),
+            (Combine::All, 7182),
+            (Combine::Sum, 18),
+            (Combine::Max, 8),
+            (Combine::Min, 1),
+            (Combine::Nth(1), 7),
+            (Combine::Nth(3), 8),
+        ] {
+            assert_eq!(values(line, combine(rules)), vec![Ok(Some(expected))]);
+        }
+        assert_eq!(
+            values(line, combine(Combine::Nth(5))),
+            vec![Err("a line with at least 5 digits".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_all_overflow() {
+        let rules = combine(Combine::All);
+        assert_eq!(
+            values(&"9".repeat(19), rules),
+            vec![Ok(Some(10u64.pow(19) - 1))]
+        );
+        assert_eq!(
+            values(&"9".repeat(20), rules),
+            vec![Err("a value within 64 bits".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_all_multi_digit_words() {
+        let matcher = Vocabulary::parse("dix 10\nonze 11").unwrap().matcher();
+        let traces = trace("xdixonze", &matcher);
+        assert_eq!(combine(Combine::All).value(&traces[0]), Ok(Some(1011)));
+        assert_eq!(Rules::default().value(&traces[0]), Ok(Some(1011)));
+    }
+
+    #[test]
+    fn test_empty() {
+        let input = "1abc2\nxyz\n3";
+        for combine in [
+            Combine::FirstLast,
+            Combine::All,
+            Combine::Sum,
+            Combine::Max,
+            Combine::Nth(2),
+        ] {
+            let rules = |empty| Rules { combine, empty };
+            let err = values(input, rules(Empty::Error)).remove(1).unwrap_err();
+            assert_eq!(err, "a line with a digit");
+            assert_eq!(values(input, rules(Empty::Skip))[1], Ok(None));
+            assert_eq!(values(input, rules(Empty::Zero))[1], Ok(Some(0)));
+        }
+    }
+
+    #[test]
+    fn test_sum_error() {
+        let matcher = Vocabulary::digits().matcher();
+        let err = Rules::default()
+            .sum(&trace("12\nab\n3", &matcher))
+            .unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (2, "ab"));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("first-last".parse(), Ok(Combine::FirstLast));
+        assert_eq!("min".parse(), Ok(Combine::Min));
+        assert_eq!("nth=3".parse(), Ok(Combine::Nth(3)));
+        assert!("nth=0".parse::<Combine>().is_err());
+        assert!("nth=x".parse::<Combine>().is_err());
+        assert!("last".parse::<Combine>().is_err());
+
+        assert_eq!("skip".parse(), Ok(Empty::Skip));
+        assert!("ignore".parse::<Empty>().is_err());
+    }
+}
@@ -0,0 +1,261 @@
+use crate::matcher::Matcher;
+use crate::rules::{Combine, Rules};
+use crate::vocabulary::unicode_digit;
+use aoc_common::parse::lines;
+use unicode_width::UnicodeWidthStr;
+
+/// How a digit was written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// A single decimal digit, eg `7` or `٣`.
+    Digit,
+    /// A word of the vocabulary, eg `seven`.
+    Word,
+}
+
+/// A digit found in a line, `start..end` being its byte range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub text: &'a str,
+    pub value: u32,
+    pub start: usize,
+    pub end: usize,
+    pub kind: Kind,
+}
+
+/// Every digit found in a line of the calibration document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineTrace<'a> {
+    /// The 1-based line number.
+    pub number: usize,
+    pub text: &'a str,
+    /// The digits, ordered by where they start, overlapping ones included.
+    pub tokens: Vec<Token<'a>>,
+}
+
+impl LineTrace<'_> {
+    /// The calibration value of the line under the puzzle's rules, failing
+    /// with what the line lacked.
+    pub fn value(&self) -> Result<u64, String> {
+        Combine::FirstLast.apply(&self.tokens)
+    }
+}
+
+/// The token a calibration value starts with and the one it ends with, the
+/// one ending last, or `None` when there are no tokens.
+pub fn first_last<'t, 'a>(tokens: &'t [Token<'a>]) -> Option<(&'t Token<'a>, &'t Token<'a>)> {
+    let first = tokens.first()?;
+    let last = tokens.iter().max_by_key(|x| x.end)?;
+    Some((first, last))
+}
+
+/// Finds every digit `matcher` knows in `line`.
+pub fn trace_line<'a>(line: &'a str, matcher: &Matcher) -> Vec<Token<'a>> {
+    matcher
+        .find_all(line)
+        .into_iter()
+        .map(|x| {
+            let text = &line[x.start..x.end];
+            let mut chars = text.chars();
+            let kind = match (chars.next().and_then(unicode_digit), chars.next()) {
+                (Some(_), None) => Kind::Digit,
+                _ => Kind::Word,
+            };
+            Token {
+                text,
+                value: x.value,
+                start: x.start,
+                end: x.end,
+                kind,
+            }
+        })
+        .collect()
+}
+
+/// Traces every line of a calibration document.
+pub fn trace<'a>(input: &'a str, matcher: &Matcher) -> Vec<LineTrace<'a>> {
+    lines(input)
+        .map(|line| LineTrace {
+            number: line.number,
+            text: line.text,
+            tokens: trace_line(line.text, matcher),
+        })
+        .collect()
+}
+
+/// Writes `marker` under the characters of `token`, measured in the columns
+/// a terminal shows them over, so wide characters such as `三` take two and
+/// combining marks none.
+fn underline(text: &str, token: &Token, marker: char) -> String {
+    let indent = text[..token.start].width();
+    let width = token.text.width().max(1);
+    format!("{}{}", " ".repeat(indent), marker.to_string().repeat(width))
+}
+
+/// Renders every line with its calibration value under `rules`, the first
+/// token underlined with `^` and the last with `~` below it, followed by every
+/// token found.
+pub fn render(traces: &[LineTrace], rules: &Rules) -> String {
+    let width = traces.last().map_or(1, |x| x.number.to_string().len());
+    let margin = " ".repeat(width + 2);
+
+    let mut text = String::new();
+    for trace in traces {
+        let value = match rules.value(trace) {
+            Ok(Some(value)) => value.to_string(),
+            Ok(None) => "skipped".to_string(),
+            Err(err) => format!("expected {}", err.expected),
+        };
+        text.push_str(&format!(
+            "{:>w$}  {}  => {}\n",
+            trace.number,
+            trace.text,
+            value,
+            w = width
+        ));
+
+        if let Some((first, last)) = first_last(&trace.tokens) {
+            text.push_str(&format!(
+                "{}{}\n",
+                margin,
+                underline(trace.text, first, '^')
+            ));
+            if first != last {
+                text.push_str(&format!("{}{}\n", margin, underline(trace.text, last, '~')));
+            }
+        }
+
+        let tokens: Vec<String> = trace
+            .tokens
+            .iter()
+            .map(|x| {
+                let kind = match x.kind {
+                    Kind::Digit => "digit",
+                    Kind::Word => "word",
+                };
+                format!("{} {} {}..{}", kind, x.text, x.start, x.end)
+            })
+            .collect();
+        if !tokens.is_empty() {
+            text.push_str(&format!("{}{}\n", margin, tokens.join(", ")));
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part2::decode_line;
+    use crate::rules::Empty;
+    use crate::vocabulary::Vocabulary;
+    use aoc_common::read_input;
+
+    fn matcher() -> Matcher {
+        Vocabulary::english().with(Vocabulary::digits()).matcher()
+    }
+
+    #[test]
+    fn test_trace_line() {
+        let tokens = trace_line("threetwoonez1gtrd", &matcher());
+        let found: Vec<(&str, u32, usize, usize, Kind)> = tokens
+            .iter()
+            .map(|x| (x.text, x.value, x.start, x.end, x.kind))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("three", 3, 0, 5, Kind::Word),
+                ("two", 2, 5, 8, Kind::Word),
+                ("one", 1, 8, 11, Kind::Word),
+                ("1", 1, 12, 13, Kind::Digit),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_overlapping() {
+        let traces = trace("eighthree", &matcher());
+        let (first, last) = first_last(&traces[0].tokens).unwrap();
+        assert_eq!((first.text, last.text), ("eight", "three"));
+        assert_eq!(traces[0].value(), Ok(83));
+    }
+
+    #[test]
+    fn test_first_last() {
+        let tokens = trace_line("threetwoonez1gtrd", &matcher());
+        let (first, last) = first_last(&tokens).unwrap();
+        assert_eq!((first.start, first.value), (0, 3));
+        assert_eq!((last.start, last.value), (12, 1));
+        assert!(first_last(&trace_line("abc", &matcher())).is_none());
+
+        let tokens = trace_line("one", &matcher());
+        let (first, last) = first_last(&tokens).unwrap();
+        assert_eq!(first, last);
+    }
+
+    #[test]
+    fn test_values_match_decoding() {
+        let matcher = matcher();
+        let input = read_input("input/example2.txt");
+        for trace in trace(&input, &matcher) {
+            assert_eq!(trace.value(), decode_line(trace.text, &matcher));
+        }
+    }
+
+    #[test]
+    fn test_unicode_digits() {
+        let matcher = Vocabulary::unicode_digits(&['\u{0660}']).matcher();
+        let tokens = trace_line("x٣y", &matcher);
+        assert_eq!((tokens[0].start, tokens[0].end), (1, 3));
+        assert_eq!(tokens[0].kind, Kind::Digit);
+    }
+
+    #[test]
+    fn test_render() {
+        let traces = trace("eighthree\nabc\n7pqr", &matcher());
+        assert_eq!(
+            render(&traces, &Rules::default()),
+            "1  eighthree  => 83
+   ^^^^^
+       ~~~~~
+   word eight 0..5, word three 4..9
+2  abc  => expected a line with a digit
+3  7pqr  => 77
+   ^
+   digit 7 0..1
+"
+        );
+
+        let rules = Rules {
+            combine: Combine::All,
+            empty: Empty::Skip,
+        };
+        let text = render(&traces, &rules);
+        let values: Vec<&str> = text.lines().filter(|x| x.contains("=>")).collect();
+        assert_eq!(
+            values,
+            vec!["1  eighthree  => 83", "2  abc  => skipped", "3  7pqr  => 7"]
+        );
+    }
+
+    #[test]
+    fn test_render_wide_characters() {
+        let matcher = Vocabulary::parse("三 3\n四 4\ne\u{301} 5")
+            .unwrap()
+            .matcher();
+        let traces = trace("x三y四\ne\u{301}z三", &matcher);
+        assert_eq!(
+            render(&traces, &Rules::default()),
+            "1  x三y四  => 34
+    ^^
+       ~~
+   word 三 1..4, word 四 5..8
+2  e\u{301}z三  => 53
+   ^
+     ~~
+   word e\u{301} 0..3, word 三 4..7
+"
+        );
+    }
+}
@@ -0,0 +1,352 @@
+use crate::matcher::Matcher;
+use aoc_common::parse::lines;
+use aoc_common::ParseError;
+use std::fmt;
+
+/// The zero of every script whose decimal digits `unicode_digit` knows, each
+/// followed by the nine other digits.
+pub const UNICODE_ZEROS: [char; 8] = [
+    '0',        // ASCII
+    '\u{0660}', // Arabic-Indic
+    '\u{06F0}', // Extended Arabic-Indic
+    '\u{0966}', // Devanagari
+    '\u{09E6}', // Bengali
+    '\u{0E50}', // Thai
+    '\u{0F20}', // Tibetan
+    '\u{FF10}', // Fullwidth
+];
+
+/// Returns the value of a decimal digit of any script in `UNICODE_ZEROS`.
+pub fn unicode_digit(c: char) -> Option<u32> {
+    UNICODE_ZEROS.iter().find_map(|zero| {
+        let value = (c as u32).checked_sub(*zero as u32)?;
+        (value < 10).then_some(value)
+    })
+}
+
+/// The words a calibration line may spell its digits with, and their values.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+}
+
+/// Two words of a vocabulary with different values that can be read from the
+/// same text, making which was meant ambiguous.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict {
+    pub word: String,
+    pub other: String,
+    pub kind: ConflictKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictKind {
+    /// Both words are the same.
+    Duplicate,
+    /// `word` starts `other`.
+    Prefix,
+    /// `word` ends `other`.
+    Suffix,
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ConflictKind::Duplicate => write!(f, "{:?} has two values", self.word),
+            ConflictKind::Prefix => write!(f, "{:?} starts {:?}", self.word, self.other),
+            ConflictKind::Suffix => write!(f, "{:?} ends {:?}", self.word, self.other),
+        }
+    }
+}
+
+impl Vocabulary {
+    pub fn new() -> Vocabulary {
+        Vocabulary { words: Vec::new() }
+    }
+
+    /// Builds a vocabulary spelling 0 to 9 with `words`, in that order.
+    fn spelled(words: [&str; 10]) -> Vocabulary {
+        words.iter().zip(0..).collect()
+    }
+
+    /// The ASCII digits `0` to `9`.
+    pub fn digits() -> Vocabulary {
+        Vocabulary::unicode_digits(&['0'])
+    }
+
+    /// The decimal digits of the scripts whose zero is listed, the ten code
+    /// points from each zero. Code points that are not characters, such as
+    /// surrogates, are skipped.
+    pub fn unicode_digits(zeros: &[char]) -> Vocabulary {
+        zeros
+            .iter()
+            .flat_map(|zero| (0..10).map(move |x| (*zero as u32 + x, x)))
+            .filter_map(|(code, value)| Some((char::from_u32(code)?.to_string(), value)))
+            .collect()
+    }
+
+    pub fn english() -> Vocabulary {
+        Vocabulary::spelled([
+            "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ])
+    }
+
+    pub fn french() -> Vocabulary {
+        Vocabulary::spelled([
+            "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+        ])
+    }
+
+    pub fn german() -> Vocabulary {
+        Vocabulary::spelled([
+            "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+        ])
+    }
+
+    pub fn spanish() -> Vocabulary {
+        Vocabulary::spelled([
+            "cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+        ])
+    }
+
+    /// The Roman numerals from `I` to `IX`, which have no zero. They conflict
+    /// with each other, `I` starting `II` for instance.
+    pub fn roman() -> Vocabulary {
+        ["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX"]
+            .iter()
+            .zip(1..)
+            .collect()
+    }
+
+    /// Parses a vocabulary with a `<word> <value>` line per word, eg `dix 10`.
+    /// Blank lines are skipped.
+    pub fn parse(input: &str) -> Result<Vocabulary, ParseError> {
+        let mut vocabulary = Vocabulary::new();
+        for line in lines(input).filter(|x| !x.text.trim().is_empty()) {
+            let mut parts = line.text.split_whitespace();
+            let word = parts.next().unwrap();
+            let value = match parts.next() {
+                Some(value) => line.number(value)?,
+                None => return Err(line.error_after(word, "a value")),
+            };
+            if let Some(extra) = parts.next() {
+                return Err(line.error_at(extra, "the end of the line"));
+            }
+            vocabulary.insert(word, value);
+        }
+        Ok(vocabulary)
+    }
+
+    pub fn insert<S: Into<String>>(&mut self, word: S, value: u32) {
+        self.words.push((word.into(), value));
+    }
+
+    /// Adds every word of `other`.
+    pub fn with(mut self, other: Vocabulary) -> Vocabulary {
+        self.words.extend(other.words);
+        self
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> + '_ {
+        self.words
+            .iter()
+            .map(|(word, value)| (word.as_str(), *value))
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Returns every pair of words with different values where one is the
+    /// other, starts it or ends it, each pair once.
+    pub fn conflicts(&self) -> Vec<Conflict> {
+        let mut conflicts: Vec<Conflict> = Vec::new();
+        for (index, (word, value)) in self.words.iter().enumerate() {
+            for (other, other_value) in &self.words[index + 1..] {
+                if value == other_value {
+                    continue;
+                }
+                let (short, long) = if word.len() <= other.len() {
+                    (word, other)
+                } else {
+                    (other, word)
+                };
+                let kind = if short == long {
+                    ConflictKind::Duplicate
+                } else if long.starts_with(short.as_str()) {
+                    ConflictKind::Prefix
+                } else if long.ends_with(short.as_str()) {
+                    ConflictKind::Suffix
+                } else {
+                    continue;
+                };
+                conflicts.push(Conflict {
+                    word: short.clone(),
+                    other: long.clone(),
+                    kind,
+                });
+            }
+        }
+        conflicts
+    }
+
+    /// Builds the automaton finding the words of the vocabulary.
+    ///
+    /// Panics if a word is empty.
+    pub fn matcher(&self) -> Matcher {
+        Matcher::new(self.words())
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<(S, u32)> for Vocabulary {
+    fn from_iter<I: IntoIterator<Item = (S, u32)>>(iter: I) -> Vocabulary {
+        let mut vocabulary = Vocabulary::new();
+        for (word, value) in iter {
+            vocabulary.insert(word.as_ref(), value);
+        }
+        vocabulary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part2::decode_line;
+
+    fn decode(vocabulary: Vocabulary, line: &str) -> u64 {
+        decode_line(line, &vocabulary.with(Vocabulary::digits()).matcher()).unwrap()
+    }
+
+    #[test]
+    fn test_english() {
+        assert!(Vocabulary::english().conflicts().is_empty());
+        assert_eq!(decode(Vocabulary::english(), "xtwone3four"), 24);
+    }
+
+    #[test]
+    fn test_french() {
+        assert!(Vocabulary::french().conflicts().is_empty());
+        assert_eq!(decode(Vocabulary::french(), "zérodeuxtrois"), 3);
+        assert_eq!(decode(Vocabulary::french(), "7huitneufx"), 79);
+    }
+
+    #[test]
+    fn test_german() {
+        assert!(Vocabulary::german().conflicts().is_empty());
+        assert_eq!(decode(Vocabulary::german(), "fünfzehn"), 55);
+        assert_eq!(decode(Vocabulary::german(), "einsiebenacht"), 18);
+    }
+
+    #[test]
+    fn test_spanish() {
+        assert!(Vocabulary::spanish().conflicts().is_empty());
+        assert_eq!(decode(Vocabulary::spanish(), "unonueve"), 19);
+        assert_eq!(decode(Vocabulary::spanish(), "cuatrosiete2"), 42);
+    }
+
+    #[test]
+    fn test_roman() {
+        let roman = Vocabulary::roman();
+        assert_eq!(roman.len(), 9);
+        let conflicts = roman.conflicts();
+        assert_eq!(
+            conflicts[0],
+            Conflict {
+                word: "I".to_string(),
+                other: "II".to_string(),
+                kind: ConflictKind::Prefix,
+            }
+        );
+        assert!(conflicts.contains(&Conflict {
+            word: "I".to_string(),
+            other: "VI".to_string(),
+            kind: ConflictKind::Suffix,
+        }));
+        // Both `I` and `IV` start the numeral, both `IV` and `V` end it
+        assert_eq!(decode(roman, "xIVy"), 15);
+    }
+
+    #[test]
+    fn test_unicode_digits() {
+        let arabic = Vocabulary::unicode_digits(&['\u{0660}']);
+        assert_eq!(arabic.len(), 10);
+        assert_eq!(decode(arabic, "a٣b٧c"), 37);
+
+        // The code points after U+D7FF are surrogates, and U+10FFFF is the last
+        assert_eq!(Vocabulary::unicode_digits(&['\u{D7FE}']).len(), 2);
+        assert_eq!(Vocabulary::unicode_digits(&['\u{10FFFD}']).len(), 3);
+
+        assert_eq!(unicode_digit('٣'), Some(3));
+        assert_eq!(unicode_digit('९'), Some(9));
+        assert_eq!(unicode_digit('７'), Some(7));
+        assert_eq!(unicode_digit('½'), None);
+        assert_eq!(unicode_digit('三'), None);
+    }
+
+    #[test]
+    fn test_mixed_languages() {
+        // `six` is French and English, with the same value either way
+        let vocabulary = Vocabulary::english()
+            .with(Vocabulary::french())
+            .with(Vocabulary::spanish());
+        assert!(vocabulary.conflicts().is_empty());
+        assert_eq!(decode(vocabulary, "sixtresun"), 61);
+
+        // But the French `un` ends the German `neun`
+        let vocabulary = Vocabulary::french().with(Vocabulary::german());
+        let conflicts: Vec<String> = vocabulary
+            .conflicts()
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        assert_eq!(conflicts, vec!["\"un\" ends \"neun\""]);
+    }
+
+    #[test]
+    fn test_conflicts() {
+        let vocabulary: Vocabulary = [("one", 1), ("one", 11), ("ne", 9), ("on", 1)]
+            .into_iter()
+            .collect();
+        let conflicts: Vec<String> = vocabulary
+            .conflicts()
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        assert_eq!(
+            conflicts,
+            vec![
+                "\"one\" has two values",
+                "\"ne\" ends \"one\"",
+                "\"ne\" ends \"one\"",
+                "\"on\" starts \"one\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let vocabulary = Vocabulary::parse("dix 10\n\nonze   11\n").unwrap();
+        let words: Vec<(&str, u32)> = vocabulary.words().collect();
+        assert_eq!(words, vec![("dix", 10), ("onze", 11)]);
+        assert_eq!(decode(vocabulary, "xdixonze"), 1011);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Vocabulary::parse("dix 10\nonze").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 5, "a value")
+        );
+
+        let err = Vocabulary::parse("dix ten").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (5, "ten"));
+
+        let err = Vocabulary::parse("dix 10 x").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (8, "x"));
+    }
+}
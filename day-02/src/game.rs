@@ -0,0 +1,189 @@
+use aoc_common::parse::{lines, Line};
+use aoc_common::ParseError;
+
+#[derive(Debug)]
+pub struct Bag {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl Bag {
+    /// Multiplies the counts of every colour, in 128 bits so that three
+    /// 32-bit counts always fit.
+    pub fn power_of_dice(&self) -> u128 {
+        let red = if self.red > 0 { self.red } else { 1 };
+        let green = if self.green > 0 { self.green } else { 1 };
+        let blue = if self.blue > 0 { self.blue } else { 1 };
+        u128::from(red) * u128::from(green) * u128::from(blue)
+    }
+}
+
+#[derive(Debug)]
+pub struct Hand {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+#[derive(Debug)]
+pub struct Game {
+    pub id: u32,
+    pub hands: Vec<Hand>,
+}
+
+impl Game {
+    /// Returns true if every hand of the game could be drawn from the bag.
+    pub fn possible(&self, bag: &Bag) -> bool {
+        for hand in &self.hands {
+            if hand.red > bag.red || hand.green > bag.green || hand.blue > bag.blue {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Returns the smallest bag every hand of the game could be drawn from.
+    pub fn minimum_viable(&self) -> Bag {
+        let mut red = 0;
+        let mut green = 0;
+        let mut blue = 0;
+
+        for hand in &self.hands {
+            if hand.red > red {
+                red = hand.red
+            };
+            if hand.green > green {
+                green = hand.green
+            };
+            if hand.blue > blue {
+                blue = hand.blue
+            };
+        }
+
+        Bag { red, green, blue }
+    }
+}
+
+/// Parses every line of the puzzle input into a game.
+pub fn generate_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    lines(input).map(generate_game).collect()
+}
+
+/// Parses a line such as `Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue`.
+pub fn generate_game(line: Line) -> Result<Game, ParseError> {
+    let (label, all_hands) = line.split_once(line.text, ':')?;
+    let id = match label.strip_prefix("Game ") {
+        Some(id) => line.number::<u32>(id)?,
+        None => return Err(line.error_at(label, "`Game <id>`")),
+    };
+
+    let mut hands: Vec<Hand> = Vec::new();
+    for hand_str in all_hands.split(';') {
+        let mut red: u32 = 0;
+        let mut green: u32 = 0;
+        let mut blue: u32 = 0;
+
+        for cubes in hand_str.split(',') {
+            let cubes = cubes.trim();
+            let Some((count, color)) = cubes.split_once(' ') else {
+                return Err(line.error_at(cubes, "`<count> <color>`"));
+            };
+            let total = match color {
+                "red" => &mut red,
+                "green" => &mut green,
+                "blue" => &mut blue,
+                _ => return Err(line.error_at(color, "one of red, green or blue")),
+            };
+            *total = total
+                .checked_add(line.number::<u32>(count)?)
+                .ok_or_else(|| line.error_at(count, "a count within 32 bits"))?;
+        }
+
+        let hand = Hand { red, green, blue };
+        hands.push(hand);
+    }
+    Ok(Game { id, hands })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(text: &str) -> Result<Game, ParseError> {
+        generate_game(Line::new(1, text))
+    }
+
+    #[test]
+    fn test_generate_game() {
+        let game = parse("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green").unwrap();
+        assert_eq!(game.id, 3);
+        assert_eq!(game.hands.len(), 2);
+        assert_eq!(
+            (game.hands[0].red, game.hands[0].green, game.hands[0].blue),
+            (20, 8, 6)
+        );
+        assert_eq!(
+            (game.hands[1].red, game.hands[1].green, game.hands[1].blue),
+            (4, 13, 5)
+        );
+    }
+
+    #[test]
+    fn test_missing_colon() {
+        let err = parse("Game 3 8 green").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (15, "`:`"));
+    }
+
+    #[test]
+    fn test_invalid_label() {
+        let err = parse("Round 3: 8 green").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (1, "Round 3"));
+        assert_eq!(err.expected, "`Game <id>`");
+    }
+
+    #[test]
+    fn test_invalid_id() {
+        let err = parse("Game three: 8 green").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (6, "three"));
+    }
+
+    #[test]
+    fn test_invalid_count() {
+        let err = parse("Game 3: 8 green; x blue").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (18, "x"));
+        assert_eq!(err.expected, "a number");
+    }
+
+    #[test]
+    fn test_invalid_color() {
+        let err = parse("Game 3: 8 green, 2 purple").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (20, "purple"));
+        assert_eq!(err.expected, "one of red, green or blue");
+    }
+
+    #[test]
+    fn test_count_overflow() {
+        let game = parse("Game 1: 4294967294 red, 1 red").unwrap();
+        assert_eq!(game.hands[0].red, u32::MAX);
+
+        let err = parse("Game 1: 4294967295 red, 1 red").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (25, "1"));
+        assert_eq!(err.expected, "a count within 32 bits");
+    }
+
+    #[test]
+    fn test_missing_color() {
+        let err = parse("Game 3: 8 green,; 1 red").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 17));
+        assert_eq!(err.found, "");
+    }
+
+    #[test]
+    fn test_error_line_number() {
+        let err = generate_games("Game 1: 1 red\nGame 2: 1 rad")
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (2, 11));
+    }
+}
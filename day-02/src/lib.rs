@@ -0,0 +1,32 @@
+//! https://adventofcode.com/2023/day/2
+
+use aoc_common::{ParseError, Solution};
+use game::Game;
+
+pub mod game;
+pub mod part1;
+pub mod part2;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+    type Answer1 = u64;
+    type Answer2 = u128;
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        game::generate_games(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> u64 {
+        part1::part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> u128 {
+        part2::part2(input)
+    }
+}
@@ -0,0 +1,61 @@
+use crate::game::{Bag, Game};
+
+/// Returns the sum of the ids of every game possible with the elf's bag, in
+/// 64 bits so that fewer than 2^32 games of 32-bit ids cannot overflow.
+pub fn part1(games: &[Game]) -> u64 {
+    let bag = Bag {
+        red: 12,
+        green: 13,
+        blue: 14,
+    };
+
+    games
+        .iter()
+        .filter(|game| game.possible(&bag))
+        .map(|m| u64::from(m.id))
+        .sum::<u64>()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::game::generate_game;
+    use aoc_common::parse::lines;
+    use aoc_common::read_input;
+
+    const EXAMPLE1: &str = "input/example1.txt";
+
+    #[test]
+    fn test_correct_answer() {
+        use super::*;
+        let input = read_input(EXAMPLE1);
+        let mut possible_games: Vec<Game> = Vec::new();
+
+        let bag = Bag {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+
+        for line in lines(&input) {
+            let game = generate_game(line).unwrap();
+            if game.possible(&bag) {
+                possible_games.push(game);
+            }
+        }
+        assert_eq!(possible_games.len(), 3);
+        let known_game_ids = vec![1, 2, 5];
+        for game in &possible_games {
+            assert!(known_game_ids.contains(&game.id));
+        }
+        for known_game_id in known_game_ids {
+            assert!(&possible_games.iter().any(|g| g.id == known_game_id));
+        }
+    }
+
+    #[test]
+    fn test_example_sum() {
+        use super::*;
+        let games = crate::game::generate_games(&read_input(EXAMPLE1)).unwrap();
+        assert_eq!(part1(&games), 8);
+    }
+}
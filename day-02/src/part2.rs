@@ -0,0 +1,63 @@
+use crate::game::Game;
+
+/// Returns the sum of the power of the minimum viable bag of every game.
+///
+/// Every power is below 2^96, so fewer than 2^32 games cannot overflow the
+/// 128-bit sum.
+pub fn part2(games: &[Game]) -> u128 {
+    let mut games_sum: u128 = 0;
+    for game in games {
+        let bag = game.minimum_viable();
+        games_sum += bag.power_of_dice()
+    }
+
+    games_sum
+}
+
+#[cfg(test)]
+mod test {
+    use crate::game::generate_games;
+    use aoc_common::read_input;
+
+    const EXAMPLE1: &str = "input/example1.txt";
+
+    #[test]
+    fn test_correct_answer() {
+        use super::*;
+        let expected: Vec<u128> = vec![48, 12, 1560, 630, 36];
+        let sum: u128 = expected.iter().sum();
+
+        let input = read_input(EXAMPLE1);
+        let games = generate_games(&input).unwrap();
+
+        for (idx, game) in games.iter().enumerate() {
+            let expected_value = expected.get(idx).unwrap();
+            let bag = game.minimum_viable();
+            assert_eq!(bag.power_of_dice(), *expected_value);
+        }
+
+        let mut games_sum = 0;
+        for game in &games {
+            let bag = game.minimum_viable();
+            games_sum += bag.power_of_dice();
+        }
+
+        assert_eq!(games_sum, sum);
+        assert_eq!(part2(&games), sum);
+    }
+
+    #[test]
+    fn test_large_power() {
+        use super::*;
+        let games = generate_games("Game 1: 5000 red, 5000 green, 5000 blue").unwrap();
+        assert_eq!(part2(&games), 125_000_000_000);
+
+        let max = u32::MAX;
+        let line = format!(
+            "Game 1: {} red, {} green, {} blue\nGame 2: 1 red",
+            max, max, max
+        );
+        let games = generate_games(&line).unwrap();
+        assert_eq!(part2(&games), u128::from(max).pow(3) + 1);
+    }
+}
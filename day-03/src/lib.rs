@@ -0,0 +1,37 @@
+//! https://adventofcode.com/2023/day/3
+
+use aoc_common::{ParseError, Solution};
+use schematic::Schematic;
+
+pub mod part1;
+pub mod part2;
+pub mod part_number;
+pub mod regions;
+pub mod render;
+pub mod rules;
+pub mod schematic;
+pub mod stream;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Schematic;
+    type Answer1 = u64;
+    type Answer2 = Result<u64, ParseError>;
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        schematic::parse_schematic(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> u64 {
+        part1::part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<u64, ParseError> {
+        part2::part2(input)
+    }
+}
@@ -0,0 +1,267 @@
+// https://adventofcode.com/2023/day/3
+
+use crate::part_number::PartNumber;
+use crate::rules::Rules;
+use crate::schematic::Schematic;
+
+/// Returns the numbers touching at least one symbol, which are the part numbers.
+pub fn part_numbers<'a>(schematic: &'a Schematic, rules: &Rules) -> Vec<&'a PartNumber> {
+    schematic
+        .numbers
+        .iter()
+        .filter(|x| !schematic.symbols_touching(x, rules).is_empty())
+        .collect()
+}
+
+/// Returns the sum of every part number of the schematic, in 64 bits as
+/// the `u32` numbers could add up past 32 bits.
+pub fn part1(schematic: &Schematic) -> u64 {
+    part_numbers(schematic, &Rules::default())
+        .iter()
+        .map(|x| u64::from(x.value))
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::schematic::parse_schematic;
+    use aoc_common::read_input;
+
+    fn schematic(file_path: &str) -> Schematic {
+        parse_schematic(&read_input(file_path)).unwrap()
+    }
+
+    fn values(schematic: &Schematic) -> Vec<u32> {
+        part_numbers(schematic, &Rules::default())
+            .iter()
+            .map(|x| x.value)
+            .collect()
+    }
+
+    /// Whether the number covering the square at `position` is a part number,
+    /// `None` when no number covers it.
+    fn part_number_at(schematic: &Schematic, (x, y): (usize, usize)) -> Option<bool> {
+        let number = schematic
+            .numbers
+            .iter()
+            .find(|number| number.row == y && number.columns().contains(&x))?;
+        Some(
+            !schematic
+                .symbols_touching(number, &Rules::default())
+                .is_empty(),
+        )
+    }
+
+    #[test]
+    fn test_board_width_height() {
+        let grid = schematic("input/example1.txt").grid;
+        assert_eq!((grid.width(), grid.height()), (10, 10));
+        assert_eq!(grid.get(9, 9), Some(&'.'));
+        assert_eq!(grid.get(10, 9), None);
+        assert_eq!(grid.get(9, 10), None);
+    }
+
+    #[test]
+    fn test_location_query() {
+        let grid = schematic("input/example1.txt").grid;
+        for (x, y) in [(0, 0), (9, 9), (9, 0), (5, 5), (7, 3)] {
+            assert_eq!(grid.positions().nth(y * grid.width() + x), Some((x, y)));
+            assert_eq!(grid[(x, y)], grid.row(y)[x]);
+        }
+    }
+
+    #[test]
+    fn test_space_squares() {
+        let grid = schematic("input/example1.txt").grid;
+        let rules = Rules::default();
+        assert!(!rules.is_blank(grid[(0, 0)])); // is 4
+        assert!(!rules.is_blank(grid[(6, 3)])); // is #
+
+        assert!(rules.is_blank(grid[(3, 0)]));
+        assert!(rules.is_blank(grid[(2, 5)]));
+        assert!(rules.is_blank(grid[(1, 7)]));
+    }
+
+    #[test]
+    fn test_symbol_squares() {
+        let grid = schematic("input/example1.txt").grid;
+        let rules = Rules::default();
+        assert!(!rules.is_symbol(grid[(0, 0)])); // is 4
+        assert!(!rules.is_symbol(grid[(3, 0)]));
+        assert!(!rules.is_symbol(grid[(2, 5)]));
+        assert!(!rules.is_symbol(grid[(1, 7)]));
+
+        assert!(rules.is_symbol(grid[(6, 3)])); // is #
+        assert!(rules.is_symbol(grid[(3, 8)])); // is $
+        assert!(rules.is_symbol(grid[(5, 8)])); // is *
+    }
+
+    #[test]
+    fn test_known_square_states() {
+        let schematic = schematic("input/example1.txt");
+        assert_eq!(part_number_at(&schematic, (0, 0)), Some(true)); // 467
+        assert_eq!(part_number_at(&schematic, (9, 9)), None);
+        assert_eq!(part_number_at(&schematic, (9, 0)), None);
+        assert_eq!(part_number_at(&schematic, (5, 5)), None); // is +
+        assert_eq!(part_number_at(&schematic, (7, 3)), None);
+    }
+
+    #[test]
+    fn test_known_valid_squares() {
+        // The digits of 114 and 58, which touch no symbol
+        let schematic = schematic("input/example1.txt");
+        for position in [(5, 0), (6, 0), (7, 0), (7, 5), (8, 5)] {
+            assert_eq!(part_number_at(&schematic, position), Some(false));
+        }
+    }
+
+    #[test]
+    fn test_known_invalid_squares() {
+        // The digits of 467, 592 and 598, which each touch a symbol
+        let schematic = schematic("input/example1.txt");
+        for position in [(0, 0), (1, 0), (2, 0), (2, 6), (3, 6), (4, 6)] {
+            assert_eq!(part_number_at(&schematic, position), Some(true));
+        }
+        for position in [(5, 9), (6, 9), (7, 9)] {
+            assert_eq!(part_number_at(&schematic, position), Some(true));
+        }
+    }
+
+    #[test]
+    fn test_known_nearby() {
+        let schematic = schematic("input/example1.txt");
+        let grid = &schematic.grid;
+        let rules = Rules::default();
+
+        // The top left corner only touches the 6 of 467 among its three neighbours
+        let nearby: Vec<(usize, usize)> = grid.neighbours8(0, 0).collect();
+        assert_eq!(nearby.len(), 3);
+        let filled: Vec<(usize, usize)> = nearby
+            .into_iter()
+            .filter(|&x| !rules.is_blank(grid[x]))
+            .collect();
+        assert_eq!(filled, vec![(1, 0)]);
+
+        // Corners have three neighbours, edges five and the rest eight
+        for ((x, y), count) in [
+            ((9, 0), 3),
+            ((0, 9), 3),
+            ((9, 9), 3),
+            ((5, 0), 5),
+            ((0, 5), 5),
+            ((9, 5), 5),
+            ((5, 9), 5),
+            ((5, 5), 8),
+        ] {
+            assert_eq!(grid.neighbours8(x, y).count(), count, "({}, {})", x, y);
+        }
+
+        // 664 on the bottom edge touches the `$` above it
+        let number = &schematic.numbers[8];
+        assert_eq!((number.value, number.row), (664, 9));
+        assert_eq!(schematic.symbols_touching(number, &rules), vec![(3, 8)]);
+    }
+
+    // From the example, there are a total of 10 groups of numbers where 2 are invalid
+    // The invalid numbers from the example are 114 and 58
+    // The known sum of all of these numbers is 4361
+    // and with valid number groups being 467, 35, 633, 617, 592, 755, 664, 598
+
+    #[test]
+    fn test_known_groups_count() {
+        let schematic = schematic("input/example1.txt");
+        assert_eq!(schematic.numbers.len(), 10);
+        assert_eq!(part_numbers(&schematic, &Rules::default()).len(), 8);
+    }
+
+    #[test]
+    fn test_known_valid_groups() {
+        let schematic = schematic("input/example1.txt");
+        assert_eq!(
+            values(&schematic),
+            vec![467, 35, 633, 617, 592, 755, 664, 598]
+        );
+    }
+
+    #[test]
+    fn test_known_example_sum() {
+        let schematic = schematic("input/example1.txt");
+        assert_eq!(part1(&schematic), 4361);
+    }
+
+    #[test]
+    fn test_2_known_example_sum() {
+        // provided by redditor i_have_no_biscuits
+        // https://www.reddit.com/r/adventofcode/comments/189q9wv/2023_day_3_another_sample_grid_to_use/
+        // who provided another example grid to test your code against
+        let schematic = schematic("input/example1_2.txt");
+        assert_eq!(part1(&schematic), 925);
+    }
+
+    #[test]
+    fn test_2_known_valid_groups() {
+        let schematic = schematic("input/example1_2.txt");
+        assert_eq!(
+            values(&schematic),
+            vec![12, 34, 12, 78, 78, 9, 23, 90, 12, 2, 2, 12, 1, 1, 503, 56]
+        );
+    }
+
+    #[test]
+    fn test_2_known_edgecases() {
+        // In example 2, the number 5 is invalid but is surrounded by valid numbers
+        // This test ensures that the number 5 is not counted as valid
+        // as only symbols make a number valid, not neighbouring numbers
+
+        let schematic = schematic("input/example1_2.txt");
+        assert!(!values(&schematic).contains(&5));
+    }
+
+    #[test]
+    fn test_2_bottom_right_corner_56() {
+        let schematic = schematic("input/example1_2.txt");
+        let corner = schematic.numbers.last().unwrap();
+        assert_eq!(
+            (corner.value, corner.row, corner.columns()),
+            (56, 11, 10..12)
+        );
+        assert_eq!(
+            schematic.symbols_touching(corner, &Rules::default()),
+            vec![(11, 10)]
+        );
+        assert!(values(&schematic).contains(&56));
+    }
+
+    #[test]
+    fn test_listed_symbols() {
+        let schematic = schematic("input/example1.txt");
+        let rules = Rules {
+            symbols: Some(vec!['#', '$']),
+            ..Rules::default()
+        };
+        let values: Vec<u32> = part_numbers(&schematic, &rules)
+            .iter()
+            .map(|x| x.value)
+            .collect();
+        assert_eq!(values, vec![633, 664]);
+    }
+
+    #[test]
+    #[ignore = "requires personal puzzle input"]
+    fn test_part1_known_invalid_answer() {
+        let sum = part1(&schematic("input/input.txt"));
+        let known_invalid = [538044];
+
+        assert!(!known_invalid.contains(&sum))
+    }
+
+    #[test]
+    #[ignore = "requires personal puzzle input"]
+    fn part_1_solution() {
+        // This test is to ensure that the solution is correct
+        let sum = part1(&schematic("input/input.txt"));
+
+        assert_eq!(sum, 560670, "Sum: {}  Expected: {}", sum, "560670");
+    }
+}
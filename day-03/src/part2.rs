@@ -0,0 +1,212 @@
+// https://adventofcode.com/2023/day/3
+
+use crate::rules::Rules;
+use crate::schematic::{Position, Schematic};
+use aoc_common::parse::Line;
+use aoc_common::ParseError;
+
+/// A gear symbol touching as many numbers as the rules require.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Gear {
+    pub position: Position,
+    /// The values of the numbers touching the gear, in the order they were written.
+    pub components: Vec<u32>,
+}
+
+impl Gear {
+    /// The product of the numbers touching the gear, `None` if it doesn't fit
+    /// in 64 bits.
+    pub fn ratio(&self) -> Option<u64> {
+        self.components
+            .iter()
+            .try_fold(1u64, |product, x| product.checked_mul(u64::from(*x)))
+    }
+}
+
+/// Returns an error pointing at the gear square at `position` of a row made
+/// of `cells`, for the first gear whose ratio, or the sum of ratios so far,
+/// doesn't fit in 64 bits.
+pub(crate) fn gear_error(cells: &[char], position: Position) -> ParseError {
+    let text: String = cells.iter().collect();
+    let (start, square) = text.char_indices().nth(position.0).unwrap();
+    let line = Line::new(position.1 + 1, &text);
+    line.error_at(
+        &text[start..start + square.len_utf8()],
+        "gear ratios that fit in 64 bits",
+    )
+}
+
+fn get_gear_squares(schematic: &Schematic, rules: &Rules) -> Vec<Position> {
+    schematic
+        .grid
+        .positions()
+        .filter(|&position| rules.is_gear(schematic.grid[position]))
+        .collect()
+}
+
+/// Loops through all gear squares and returns their gears.
+/// A gear square is only a gear when exactly `rules.gear_numbers` numbers
+/// are adjacent to it.
+pub fn gears(schematic: &Schematic, rules: &Rules) -> Vec<Gear> {
+    let mut gears: Vec<Gear> = Vec::new();
+
+    for gear_square in get_gear_squares(schematic, rules) {
+        let numbers = schematic.numbers_touching(gear_square);
+        if numbers.len() == rules.gear_numbers {
+            let components: Vec<u32> = numbers.iter().map(|x| x.value).collect();
+            gears.push(Gear {
+                position: gear_square,
+                components,
+            });
+        }
+    }
+    gears
+}
+
+/// Fails with [`gear_error`] at the first gear that overflows.
+fn sum_gear_ratios(schematic: &Schematic, groups: &Vec<Gear>) -> Result<u64, ParseError> {
+    let mut sum: u64 = 0;
+    for group in groups {
+        sum = group
+            .ratio()
+            .and_then(|x| sum.checked_add(x))
+            .ok_or_else(|| gear_error(schematic.grid.row(group.position.1), group.position))?;
+    }
+    Ok(sum)
+}
+
+/// Returns the sum of the gear ratios of the schematic.
+pub fn part2(schematic: &Schematic) -> Result<u64, ParseError> {
+    sum_gear_ratios(schematic, &gears(schematic, &Rules::default()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::schematic::parse_schematic;
+    use crate::stream::{self, StreamError};
+    use aoc_common::read_input;
+
+    fn schematic(file_path: &str) -> Schematic {
+        parse_schematic(&read_input(file_path)).unwrap()
+    }
+
+    #[test]
+    fn test_example1_known_gears() {
+        let gears = gears(&schematic("input/example1.txt"), &Rules::default());
+        assert_eq!(
+            gears,
+            vec![
+                Gear {
+                    position: (3, 1),
+                    components: vec![467, 35]
+                },
+                Gear {
+                    position: (5, 8),
+                    components: vec![755, 598]
+                },
+            ]
+        );
+        let ratios: Vec<Option<u64>> = gears.iter().map(|x| x.ratio()).collect();
+        assert_eq!(ratios, vec![Some(16345), Some(451490)]);
+    }
+
+    #[test]
+    fn test_example1_known_gear_ratio_sum() {
+        let sum = part2(&schematic("input/example1.txt"));
+        assert_eq!(sum, Ok(467835));
+    }
+
+    #[test]
+    #[ignore = "requires personal puzzle input"]
+    fn test_part1_known_gear_ratio_sum() {
+        let sum = part2(&schematic("input/input.txt"));
+        assert_eq!(sum, Ok(91622824));
+    }
+
+    #[test]
+    fn test_example1_known_gear_squares() {
+        let squares = get_gear_squares(&schematic("input/example1.txt"), &Rules::default());
+        assert_eq!(squares, vec![(3, 1), (3, 4), (5, 8)]);
+    }
+
+    #[test]
+    fn test_example1_known_groups() {
+        let schematic = schematic("input/example1.txt");
+        let known_groups: Vec<u32> = vec![467, 114, 35, 633, 617, 58, 592, 755, 664, 598];
+        let values: Vec<u32> = schematic.numbers.iter().map(|x| x.value).collect();
+
+        assert_eq!(values, known_groups);
+    }
+
+    #[test]
+    fn test_2_known_gear_ratio_sum() {
+        // The example has a `*` touching three numbers, which is not a gear
+        let schematic = schematic("input/example1_2.txt");
+        assert_eq!(part2(&schematic), Ok(6756));
+
+        let at_least_two: Vec<Gear> = (2..=8)
+            .flat_map(|gear_numbers| {
+                let rules = Rules {
+                    gear_numbers,
+                    ..Rules::default()
+                };
+                gears(&schematic, &rules)
+            })
+            .collect();
+        assert_eq!(sum_gear_ratios(&schematic, &at_least_two), Ok(31600));
+    }
+
+    #[test]
+    fn test_2_three_numbers() {
+        let rules = Rules {
+            gear_numbers: 3,
+            ..Rules::default()
+        };
+        let gears = gears(&schematic("input/example1_2.txt"), &rules);
+        assert_eq!(gears.len(), 1);
+        assert_eq!(gears[0].components.len(), 3);
+    }
+
+    #[test]
+    fn test_other_gear_symbol() {
+        let rules = Rules {
+            gear: '$',
+            gear_numbers: 1,
+            ..Rules::default()
+        };
+        let gears = gears(&schematic("input/example1.txt"), &rules);
+        assert_eq!(
+            gears,
+            vec![Gear {
+                position: (3, 8),
+                components: vec![664]
+            }]
+        );
+    }
+
+    #[test]
+    fn test_ratio_overflow() {
+        let schematic = parse_schematic("4000000000*4000000000").unwrap();
+        assert_eq!(part2(&schematic), Ok(16_000_000_000_000_000_000));
+
+        let schematic = parse_schematic("4000000000*4000000000\n...........2.........").unwrap();
+        let rules = Rules {
+            gear_numbers: 3,
+            ..Rules::default()
+        };
+        assert_eq!(gears(&schematic, &rules)[0].ratio(), None);
+
+        let blank = ".".repeat(21);
+        let input = format!("4000000000*4000000000\n{}\n4000000000*4000000000", blank);
+        let err = part2(&parse_schematic(&input).unwrap()).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (3, 11, "*"));
+        assert_eq!(err.expected, "gear ratios that fit in 64 bits");
+
+        let err = stream::stream(input.as_bytes(), &Rules::default(), |_| {}).unwrap_err();
+        let StreamError::Parse(streamed) = err else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((streamed.line, streamed.column), (3, 11));
+    }
+}
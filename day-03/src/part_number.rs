@@ -0,0 +1,157 @@
+use std::ops::Range;
+
+/// A number written on the schematic, with where it was written.
+///
+/// The digits cover the columns `col_start..col_end` of `row`, the end being
+/// exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartNumber {
+    pub value: u32,
+    pub row: usize,
+    pub col_start: usize,
+    pub col_end: usize,
+}
+
+impl PartNumber {
+    pub fn columns(&self) -> Range<usize> {
+        self.col_start..self.col_end
+    }
+
+    /// Returns true if `(x, y)` is one of the squares surrounding the number,
+    /// diagonals included. The digits themselves do not touch the number.
+    pub fn touches(&self, (x, y): (usize, usize)) -> bool {
+        let rows = self.row.saturating_sub(1)..=self.row + 1;
+        let columns = self.col_start.saturating_sub(1)..=self.col_end;
+        rows.contains(&y) && columns.contains(&x) && !(y == self.row && self.columns().contains(&x))
+    }
+
+    /// Iterates over the squares surrounding the number, row by row.
+    ///
+    /// Squares left of or above the grid are left out, but not those right of
+    /// or below it, as a number doesn't know how large the grid is.
+    pub fn border(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let rows = self.row.saturating_sub(1)..=self.row + 1;
+        rows.flat_map(move |y| {
+            (self.col_start.saturating_sub(1)..=self.col_end).map(move |x| (x, y))
+        })
+        .filter(move |&position| self.touches(position))
+    }
+}
+
+/// Scans `row` for runs of digits, from left to right.
+///
+/// Fails with the columns of the first number that does not fit in a `u32`.
+pub fn scan_row(row: usize, cells: &[char]) -> Result<Vec<PartNumber>, Range<usize>> {
+    let mut numbers: Vec<PartNumber> = Vec::new();
+    let mut current: Option<(usize, Option<u32>)> = None;
+
+    for (x, cell) in cells.iter().chain(std::iter::once(&'.')).enumerate() {
+        match (cell.to_digit(10), current) {
+            (Some(digit), None) => current = Some((x, Some(digit))),
+            (Some(digit), Some((start, value))) => {
+                let value = value.and_then(|v| v.checked_mul(10)?.checked_add(digit));
+                current = Some((start, value));
+            }
+            (None, Some((start, value))) => {
+                let value = value.ok_or(start..x)?;
+                numbers.push(PartNumber {
+                    value,
+                    row,
+                    col_start: start,
+                    col_end: x,
+                });
+                current = None;
+            }
+            (None, None) => {}
+        }
+    }
+
+    Ok(numbers)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn cells(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    #[test]
+    fn test_scan_row() {
+        let numbers = scan_row(4, &cells("467..114.9")).unwrap();
+        assert_eq!(
+            numbers,
+            vec![
+                PartNumber {
+                    value: 467,
+                    row: 4,
+                    col_start: 0,
+                    col_end: 3
+                },
+                PartNumber {
+                    value: 114,
+                    row: 4,
+                    col_start: 5,
+                    col_end: 8
+                },
+                PartNumber {
+                    value: 9,
+                    row: 4,
+                    col_start: 9,
+                    col_end: 10
+                },
+            ]
+        );
+        assert!(scan_row(0, &cells("...*..")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_scan_row_only_ascii_digits() {
+        let numbers = scan_row(0, &cells("1٣2")).unwrap();
+        let values: Vec<u32> = numbers.iter().map(|x| x.value).collect();
+        assert_eq!(values, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_scan_row_overflow() {
+        assert_eq!(scan_row(0, &cells("..99999999999*1")), Err(2..13));
+        assert!(scan_row(0, &cells("4294967295")).is_ok());
+    }
+
+    #[test]
+    fn test_touches() {
+        let number = PartNumber {
+            value: 35,
+            row: 2,
+            col_start: 2,
+            col_end: 4,
+        };
+        assert!(number.touches((1, 1)));
+        assert!(number.touches((4, 3)));
+        assert!(number.touches((1, 2)));
+        assert!(!number.touches((2, 2)));
+        assert!(!number.touches((5, 2)));
+        assert!(!number.touches((3, 0)));
+    }
+
+    #[test]
+    fn test_border() {
+        let number = PartNumber {
+            value: 4,
+            row: 0,
+            col_start: 0,
+            col_end: 1,
+        };
+        let border: Vec<(usize, usize)> = number.border().collect();
+        assert_eq!(border, vec![(1, 0), (0, 1), (1, 1)]);
+
+        let number = PartNumber {
+            value: 35,
+            row: 2,
+            col_start: 2,
+            col_end: 4,
+        };
+        assert_eq!(number.border().count(), 10);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::rules::Rules;
+use crate::schematic::Schematic;
+use aoc_common::grid::Components;
+use aoc_common::Connectivity;
+
+/// Labels the clusters of touching symbols.
+pub fn symbol_clusters(
+    schematic: &Schematic,
+    rules: &Rules,
+    connectivity: Connectivity,
+) -> Components {
+    schematic
+        .grid
+        .components(connectivity, |x| rules.is_symbol(*x))
+}
+
+/// Labels the clusters of touching gear symbols, each of which is a gear
+/// when it touches as many numbers as the rules require.
+pub fn gear_clusters(
+    schematic: &Schematic,
+    rules: &Rules,
+    connectivity: Connectivity,
+) -> Components {
+    schematic
+        .grid
+        .components(connectivity, |x| rules.is_gear(*x))
+}
+
+/// Labels the blobs of touching digits. Unlike part numbers, which are read
+/// along a single row, a blob may span several rows.
+pub fn digit_blobs(schematic: &Schematic, connectivity: Connectivity) -> Components {
+    schematic
+        .grid
+        .components(connectivity, |x| x.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::schematic::parse_schematic;
+    use aoc_common::read_input;
+
+    fn schematic(file_path: &str) -> Schematic {
+        parse_schematic(&read_input(file_path)).unwrap()
+    }
+
+    #[test]
+    fn test_symbol_clusters() {
+        let schematic = schematic("input/example1.txt");
+        let clusters = symbol_clusters(&schematic, &Rules::default(), Connectivity::Eight);
+        assert_eq!(clusters.regions.len(), 6);
+        assert!(clusters.regions.iter().all(|x| x.size() == 1));
+    }
+
+    #[test]
+    fn test_gear_clusters() {
+        let schematic = parse_schematic("1**.\n..*.\n.*.2").unwrap();
+        let rules = Rules::default();
+        let clusters = gear_clusters(&schematic, &rules, Connectivity::Four);
+        let cells: Vec<&Vec<(usize, usize)>> = clusters.regions.iter().map(|x| &x.cells).collect();
+        assert_eq!(cells, vec![&vec![(1, 0), (2, 0), (2, 1)], &vec![(1, 2)]]);
+        assert_eq!(
+            gear_clusters(&schematic, &rules, Connectivity::Eight)
+                .regions
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_digit_blobs_single_rows() {
+        // Every number of the first example stands on its own
+        let schematic = schematic("input/example1.txt");
+        let blobs = digit_blobs(&schematic, Connectivity::Eight);
+        assert_eq!(blobs.regions.len(), schematic.numbers.len());
+        for (blob, number) in blobs.regions.iter().zip(&schematic.numbers) {
+            assert_eq!(blob.min, (number.col_start, number.row));
+            assert_eq!(blob.width(), number.columns().len());
+        }
+    }
+
+    #[test]
+    fn test_digit_blobs_across_rows() {
+        // The `78`, `5` and `8` on the left of the second example touch
+        // diagonally, the `5` and `78` above it orthogonally as well
+        let schematic = schematic("input/example1_2.txt");
+
+        let eight = digit_blobs(&schematic, Connectivity::Eight);
+        let blob = &eight.regions[eight.labels[(0, 5)].unwrap()];
+        assert_eq!(blob.cells, vec![(0, 5), (1, 5), (1, 6), (0, 7)]);
+        assert_eq!((blob.min, blob.max), ((0, 5), (1, 7)));
+
+        let four = digit_blobs(&schematic, Connectivity::Four);
+        let blob = &four.regions[four.labels[(0, 5)].unwrap()];
+        assert_eq!(blob.cells, vec![(0, 5), (1, 5), (1, 6)]);
+        assert_ne!(four.labels[(0, 7)], four.labels[(0, 5)]);
+    }
+}
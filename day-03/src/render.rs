@@ -0,0 +1,326 @@
+use crate::part1::part_numbers;
+use crate::part2::{gears, Gear};
+use crate::rules::Rules;
+use crate::schematic::Schematic;
+use aoc_common::Grid;
+
+/// What the answers made of a square.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mark {
+    /// A blank, a symbol that is not a gear, or anything else.
+    Plain,
+    /// A digit of a part number, counted in part 1.
+    Counted,
+    /// A digit of a number touching no symbol.
+    Uncounted,
+    /// A gear, counted in part 2.
+    Gear,
+}
+
+/// A schematic with every square marked.
+#[derive(Debug, PartialEq)]
+pub struct Annotation {
+    pub squares: Grid<(char, Mark)>,
+    pub gears: Vec<Gear>,
+}
+
+impl Annotation {
+    /// Splits row `y` in runs of squares marked the same.
+    fn runs(&self, y: usize) -> Vec<(Mark, String)> {
+        let mut runs: Vec<(Mark, String)> = Vec::new();
+        for &(square, mark) in self.squares.row(y) {
+            match runs.last_mut() {
+                Some((last, text)) if *last == mark && mark != Mark::Gear => text.push(square),
+                _ => runs.push((mark, square.to_string())),
+            }
+        }
+        runs
+    }
+
+    /// Returns the gears of row `y`, from left to right.
+    fn gears_of(&self, y: usize) -> impl Iterator<Item = &Gear> + '_ {
+        self.gears.iter().filter(move |x| x.position.1 == y)
+    }
+}
+
+/// Marks which numbers and gears the answers count under `rules`.
+pub fn annotate(schematic: &Schematic, rules: &Rules) -> Annotation {
+    let mut marks: Grid<Mark> = schematic.grid.map(|_| Mark::Plain);
+    for number in &schematic.numbers {
+        for x in number.columns() {
+            marks[(x, number.row)] = Mark::Uncounted;
+        }
+    }
+    for number in part_numbers(schematic, rules) {
+        for x in number.columns() {
+            marks[(x, number.row)] = Mark::Counted;
+        }
+    }
+
+    let gears = gears(schematic, rules);
+    for gear in &gears {
+        marks[gear.position] = Mark::Gear;
+    }
+
+    let squares = schematic
+        .grid
+        .positions()
+        .map(|position| (schematic.grid[position], marks[position]))
+        .collect();
+    Annotation {
+        squares: Grid::new(schematic.grid.width(), schematic.grid.height(), squares),
+        gears,
+    }
+}
+
+const ANSI_RESET: &str = "\x1b[0m";
+
+fn ansi_colour(mark: Mark) -> Option<&'static str> {
+    match mark {
+        Mark::Plain => None,
+        Mark::Counted => Some("\x1b[32m"),
+        Mark::Uncounted => Some("\x1b[31m"),
+        Mark::Gear => Some("\x1b[33m"),
+    }
+}
+
+/// Renders the schematic for a terminal, part numbers in green, other numbers
+/// in red and gears in yellow, with the ratios of the gears of every row
+/// written after it.
+pub fn render_ansi(annotation: &Annotation) -> String {
+    let mut text = String::new();
+    for y in 0..annotation.squares.height() {
+        for (mark, run) in annotation.runs(y) {
+            match ansi_colour(mark) {
+                Some(colour) => text.push_str(&format!("{}{}{}", colour, run, ANSI_RESET)),
+                None => text.push_str(&run),
+            }
+        }
+
+        let ratios: Vec<String> = annotation.gears_of(y).map(ratio).collect();
+        if !ratios.is_empty() {
+            let colour = ansi_colour(Mark::Gear).unwrap();
+            text.push_str(&format!("  {}{}{}", colour, ratios.join(" "), ANSI_RESET));
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// Escapes the characters with a meaning in HTML and SVG.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn class(mark: Mark) -> &'static str {
+    match mark {
+        Mark::Plain => "plain",
+        Mark::Counted => "counted",
+        Mark::Uncounted => "uncounted",
+        Mark::Gear => "gear",
+    }
+}
+
+/// The ratio of a gear, written as the product of its numbers when it doesn't
+/// fit in 64 bits.
+fn ratio(gear: &Gear) -> String {
+    match gear.ratio() {
+        Some(ratio) => ratio.to_string(),
+        None => {
+            let components: Vec<String> = gear.components.iter().map(|x| x.to_string()).collect();
+            components.join("×")
+        }
+    }
+}
+
+/// The gear at `(x, y)`, when the run starting there is one.
+fn gear_at(annotation: &Annotation, x: usize, y: usize) -> Option<&Gear> {
+    annotation.gears_of(y).find(|gear| gear.position.0 == x)
+}
+
+/// Renders the schematic as a standalone HTML page, the ratio of a gear
+/// showing when hovering it.
+pub fn render_html(annotation: &Annotation) -> String {
+    let mut rows: Vec<String> = Vec::new();
+    for y in 0..annotation.squares.height() {
+        let mut row = String::new();
+        let mut x = 0;
+        for (mark, run) in annotation.runs(y) {
+            match (mark, gear_at(annotation, x, y)) {
+                (Mark::Plain, _) => row.push_str(&escape(&run)),
+                (Mark::Gear, Some(gear)) => row.push_str(&format!(
+                    "<span class=\"gear\" title=\"{}\">{}</span>",
+                    ratio(gear),
+                    escape(&run)
+                )),
+                _ => row.push_str(&format!(
+                    "<span class=\"{}\">{}</span>",
+                    class(mark),
+                    escape(&run)
+                )),
+            }
+            x += run.chars().count();
+        }
+        rows.push(row);
+    }
+
+    format!(
+        "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>Engine schematic</title>
+<style>
+.counted {{ color: #2e7d32; }}
+.uncounted {{ color: #c62828; }}
+.gear {{ color: #b8860b; font-weight: bold; }}
+</style>
+</head>
+<body>
+<pre>
+{}
+</pre>
+</body>
+</html>
+",
+        rows.join("\n")
+    )
+}
+
+const SVG_CELL_WIDTH: usize = 10;
+const SVG_CELL_HEIGHT: usize = 18;
+
+fn svg_colour(mark: Mark) -> &'static str {
+    match mark {
+        Mark::Plain => "#555555",
+        Mark::Counted => "#2e7d32",
+        Mark::Uncounted => "#c62828",
+        Mark::Gear => "#b8860b",
+    }
+}
+
+/// Renders the schematic as an SVG image, the ratio of a gear showing when
+/// hovering it.
+pub fn render_svg(annotation: &Annotation) -> String {
+    let width = annotation.squares.width() * SVG_CELL_WIDTH;
+    let height = annotation.squares.height() * SVG_CELL_HEIGHT;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" font-family=\"monospace\" font-size=\"14\">\n",
+        width, height
+    );
+    svg.push_str(&format!(
+        "<rect width=\"{}\" height=\"{}\" fill=\"white\"/>\n",
+        width, height
+    ));
+
+    for y in 0..annotation.squares.height() {
+        let mut x = 0;
+        for (mark, run) in annotation.runs(y) {
+            let length = run.chars().count();
+            let title = match gear_at(annotation, x, y) {
+                Some(gear) if mark == Mark::Gear => format!("<title>{}</title>", ratio(gear)),
+                _ => String::new(),
+            };
+            svg.push_str(&format!(
+                "<text x=\"{}\" y=\"{}\" fill=\"{}\" textLength=\"{}\" xml:space=\"preserve\">{}{}</text>\n",
+                x * SVG_CELL_WIDTH,
+                (y + 1) * SVG_CELL_HEIGHT - SVG_CELL_HEIGHT / 4,
+                svg_colour(mark),
+                length * SVG_CELL_WIDTH,
+                title,
+                escape(&run)
+            ));
+            x += length;
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::schematic::parse_schematic;
+    use aoc_common::read_input;
+
+    fn example() -> Annotation {
+        let schematic = parse_schematic(&read_input("input/example1.txt")).unwrap();
+        annotate(&schematic, &Rules::default())
+    }
+
+    #[test]
+    fn test_annotate() {
+        let annotation = example();
+        assert_eq!(annotation.squares[(0, 0)], ('4', Mark::Counted));
+        assert_eq!(annotation.squares[(5, 0)], ('1', Mark::Uncounted));
+        assert_eq!(annotation.squares[(3, 1)], ('*', Mark::Gear));
+        assert_eq!(annotation.squares[(3, 4)], ('*', Mark::Plain));
+        assert_eq!(annotation.squares[(6, 3)], ('#', Mark::Plain));
+        assert_eq!(annotation.gears.len(), 2);
+    }
+
+    #[test]
+    fn test_runs() {
+        let annotation = example();
+        assert_eq!(
+            annotation.runs(0),
+            vec![
+                (Mark::Counted, "467".to_string()),
+                (Mark::Plain, "..".to_string()),
+                (Mark::Uncounted, "114".to_string()),
+                (Mark::Plain, "..".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_ansi() {
+        let text = render_ansi(&example());
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 10);
+        assert_eq!(lines[0], "\x1b[32m467\x1b[0m..\x1b[31m114\x1b[0m..");
+        assert_eq!(lines[1], "...\x1b[33m*\x1b[0m......  \x1b[33m16345\x1b[0m");
+    }
+
+    #[test]
+    fn test_render_html() {
+        let html = render_html(&example());
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains(
+            "\n<span class=\"counted\">467</span>..<span class=\"uncounted\">114</span>..\n"
+        ));
+        assert!(html.contains("...<span class=\"gear\" title=\"16345\">*</span>......\n"));
+        assert!(html.contains("\n...$.<span class=\"gear\" title=\"451490\">*</span>....\n"));
+    }
+
+    #[test]
+    fn test_render_svg() {
+        let svg = render_svg(&example());
+        assert!(svg
+            .starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"100\" height=\"180\""));
+        assert!(svg.contains(
+            "<text x=\"0\" y=\"14\" fill=\"#2e7d32\" textLength=\"30\" xml:space=\"preserve\">467</text>"
+        ));
+        assert!(svg.contains(
+            "<text x=\"30\" y=\"32\" fill=\"#b8860b\" textLength=\"10\" xml:space=\"preserve\"><title>16345</title>*</text>"
+        ));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("1<2&\"3\">"), "1&lt;2&amp;&quot;3&quot;&gt;");
+    }
+}
@@ -0,0 +1,97 @@
+/// What the squares of a schematic mean, and what makes a gear.
+///
+/// Digits always belong to numbers, whatever the rules say.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    /// The squares that are empty.
+    pub blanks: Vec<char>,
+    /// The squares that are symbols, or `None` for any square that is neither
+    /// a digit nor a blank. Squares that are none of these are ignored.
+    pub symbols: Option<Vec<char>>,
+    /// The symbol that may be a gear.
+    pub gear: char,
+    /// How many numbers must touch a gear symbol for it to be a gear.
+    pub gear_numbers: usize,
+}
+
+impl Default for Rules {
+    /// The rules of the puzzle: `.` is blank, anything else is a symbol and a
+    /// `*` touching exactly two numbers is a gear.
+    fn default() -> Rules {
+        Rules {
+            blanks: vec!['.'],
+            symbols: None,
+            gear: '*',
+            gear_numbers: 2,
+        }
+    }
+}
+
+impl Rules {
+    pub fn is_blank(&self, square: char) -> bool {
+        !square.is_ascii_digit() && self.blanks.contains(&square)
+    }
+
+    pub fn is_symbol(&self, square: char) -> bool {
+        if square.is_ascii_digit() || self.is_blank(square) {
+            return false;
+        }
+        match &self.symbols {
+            Some(symbols) => symbols.contains(&square),
+            None => true,
+        }
+    }
+
+    /// Returns true if `square` could be a gear, which depends on the numbers
+    /// touching it as well.
+    pub fn is_gear(&self, square: char) -> bool {
+        square == self.gear && self.is_symbol(square)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let rules = Rules::default();
+        assert!(rules.is_blank('.'));
+        assert!(!rules.is_blank('#'));
+        assert!(rules.is_symbol('#'));
+        assert!(rules.is_symbol('*'));
+        assert!(!rules.is_symbol('.'));
+        assert!(!rules.is_symbol('7'));
+        assert!(rules.is_gear('*'));
+        assert!(!rules.is_gear('#'));
+    }
+
+    #[test]
+    fn test_listed_symbols() {
+        let rules = Rules {
+            blanks: vec!['.', ' '],
+            symbols: Some(vec!['#', '@']),
+            gear: '@',
+            gear_numbers: 3,
+        };
+        assert!(rules.is_blank(' '));
+        assert!(rules.is_symbol('@'));
+        assert!(!rules.is_symbol('*'));
+        assert!(!rules.is_symbol(' '));
+        assert!(rules.is_gear('@'));
+        assert!(!rules.is_gear('*'));
+    }
+
+    #[test]
+    fn test_digits_are_neither_blanks_nor_symbols() {
+        let rules = Rules {
+            blanks: vec!['0'],
+            symbols: Some(vec!['1']),
+            gear: '1',
+            ..Rules::default()
+        };
+        assert!(!rules.is_blank('0'));
+        assert!(!rules.is_symbol('1'));
+        assert!(!rules.is_gear('1'));
+    }
+}
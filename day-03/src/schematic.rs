@@ -0,0 +1,180 @@
+use crate::part_number::{scan_row, PartNumber};
+use crate::rules::Rules;
+use aoc_common::parse::{lines, Line};
+use aoc_common::{Grid, ParseError};
+
+/// A square of the schematic as `(x, y)`, `x` being its column and `y` its row.
+pub type Position = (usize, usize);
+
+/// The engine schematic, a rectangular grid of characters.
+#[derive(Debug, PartialEq)]
+pub struct Schematic {
+    pub grid: Grid<char>,
+    /// Every number of the schematic, row by row and from left to right.
+    pub numbers: Vec<PartNumber>,
+    /// The index in `numbers` of the number covering every square.
+    number_at: Grid<Option<usize>>,
+}
+
+impl Schematic {
+    /// Returns the positions of the symbols touching `number`, row by row.
+    pub fn symbols_touching(&self, number: &PartNumber, rules: &Rules) -> Vec<Position> {
+        number
+            .border()
+            .filter(|&(x, y)| self.grid.get(x, y).is_some_and(|x| rules.is_symbol(*x)))
+            .collect()
+    }
+
+    /// Returns the numbers touching the square at `position`, each once, in
+    /// the order they were written.
+    pub fn numbers_touching(&self, (x, y): Position) -> Vec<&PartNumber> {
+        let mut indices: Vec<usize> = self
+            .grid
+            .neighbours8(x, y)
+            .filter_map(|position| self.number_at[position])
+            .collect();
+        indices.sort();
+        indices.dedup();
+        indices.into_iter().map(|x| &self.numbers[x]).collect()
+    }
+}
+
+/// Parses the puzzle input into a schematic.
+///
+/// Every line must be as wide as the first one, as the schematic is a grid.
+pub fn parse_schematic(input: &str) -> Result<Schematic, ParseError> {
+    let mut rows: Vec<Vec<char>> = Vec::new();
+    let mut numbers: Vec<PartNumber> = Vec::new();
+
+    for line in lines(input) {
+        let width = rows.first().map(|x| x.len());
+        let (row, found) = parse_row(&line, rows.len(), width)?;
+        numbers.extend(found);
+        rows.push(row);
+    }
+
+    if rows.is_empty() {
+        return Err(aoc_common::parse::end_of_input(input).error("a schematic"));
+    }
+
+    let grid = Grid::from_rows(rows);
+    let mut number_at = grid.map(|_| None);
+    for (index, number) in numbers.iter().enumerate() {
+        for x in number.columns() {
+            number_at[(x, number.row)] = Some(index);
+        }
+    }
+
+    Ok(Schematic {
+        grid,
+        numbers,
+        number_at,
+    })
+}
+
+/// Parses `line` as row `y` of a schematic, with the numbers written on it.
+///
+/// The row must be `width` squares wide, when already known.
+pub(crate) fn parse_row(
+    line: &Line,
+    y: usize,
+    width: Option<usize>,
+) -> Result<(Vec<char>, Vec<PartNumber>), ParseError> {
+    let row: Vec<char> = line.text.chars().collect();
+    if let Some(width) = width {
+        let expected = format!("a row of {} squares", width);
+        if row.len() < width {
+            return Err(line.error_after(line.text, expected));
+        } else if row.len() > width {
+            let (extra, _) = line.text.char_indices().nth(width).unwrap();
+            return Err(line.error_at(&line.text[extra..], expected));
+        }
+    }
+
+    match scan_row(y, &row) {
+        Ok(numbers) => Ok((row, numbers)),
+        Err(columns) => {
+            let mut offsets = line.text.char_indices().map(|x| x.0);
+            let start = offsets.nth(columns.start).unwrap();
+            let end = offsets.nth(columns.len() - 1).unwrap_or(line.text.len());
+            Err(line.error_at(&line.text[start..end], "a number within 32 bits"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::read_input;
+
+    #[test]
+    fn test_parse_schematic() {
+        let schematic = parse_schematic("467.\n...*\n").unwrap();
+        assert_eq!(schematic.grid.height(), 2);
+        assert_eq!(schematic.grid.row(1), &['.', '.', '.', '*']);
+    }
+
+    #[test]
+    fn test_parse_short_row() {
+        let err = parse_schematic("467.\n...*\n..").unwrap_err();
+        assert_eq!(err, ParseError::new(3, 3, "a row of 4 squares", "", ".."));
+    }
+
+    #[test]
+    fn test_parse_long_row() {
+        let err = parse_schematic("467.\n...*..\n....").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 5, "a row of 4 squares", "..", "...*..")
+        );
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        let schematic = parse_schematic("467.\n..*3\n").unwrap();
+        let values: Vec<(u32, usize)> =
+            schematic.numbers.iter().map(|x| (x.value, x.row)).collect();
+        assert_eq!(values, vec![(467, 0), (3, 1)]);
+    }
+
+    #[test]
+    fn test_parse_number_too_large() {
+        let err = parse_schematic("1............\n.99999999999*\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.found, "99999999999");
+        assert_eq!(err.expected, "a number within 32 bits");
+    }
+
+    #[test]
+    fn test_symbols_touching() {
+        let schematic = parse_schematic(&read_input("input/example1.txt")).unwrap();
+        let rules = Rules::default();
+        let first = &schematic.numbers[0]; // 467
+        assert_eq!(schematic.symbols_touching(first, &rules), vec![(3, 1)]);
+        let second = &schematic.numbers[1]; // 114
+        assert!(schematic.symbols_touching(second, &rules).is_empty());
+
+        let rules = Rules {
+            symbols: Some(vec!['#']),
+            ..Rules::default()
+        };
+        assert!(schematic.symbols_touching(first, &rules).is_empty());
+    }
+
+    #[test]
+    fn test_numbers_touching() {
+        let schematic = parse_schematic(&read_input("input/example1.txt")).unwrap();
+        let values: Vec<u32> = schematic
+            .numbers_touching((3, 1))
+            .iter()
+            .map(|x| x.value)
+            .collect();
+        assert_eq!(values, vec![467, 35]);
+        assert!(schematic.numbers_touching((9, 9)).is_empty());
+    }
+
+    #[test]
+    fn test_parse_empty_schematic() {
+        assert!(parse_schematic("").is_err());
+    }
+}
@@ -0,0 +1,274 @@
+use crate::part2::{gear_error, Gear};
+use crate::part_number::PartNumber;
+use crate::rules::Rules;
+use crate::schematic::parse_row;
+use aoc_common::parse::Line;
+use aoc_common::ParseError;
+use std::fmt;
+use std::io::{self, BufRead};
+
+/// Something found while streaming a schematic, in the order the rows are
+/// read: the part numbers of a row, then its gears.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    PartNumber(PartNumber),
+    Gear(Gear),
+}
+
+/// The answers of both parts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Totals {
+    pub part1: u64,
+    pub part2: u64,
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    /// The schematic could not be read.
+    Io(io::Error),
+    /// A row of the schematic is invalid.
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "{}", err),
+            StreamError::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> StreamError {
+        StreamError::Io(err)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(err: ParseError) -> StreamError {
+        StreamError::Parse(err)
+    }
+}
+
+/// A row of the window, with the numbers written on it.
+struct Row {
+    y: usize,
+    cells: Vec<char>,
+    numbers: Vec<PartNumber>,
+}
+
+/// Finds the part numbers and gears of a schematic row by row, only ever
+/// holding the row being looked at and the rows above and below it.
+///
+/// Events come in the same order as `part1::part_numbers` and `part2::gears`
+/// list them, and rows are validated as `parse_schematic` does.
+pub fn stream<R: BufRead>(
+    reader: R,
+    rules: &Rules,
+    mut emit: impl FnMut(Event),
+) -> Result<Totals, StreamError> {
+    let mut totals = Totals::default();
+    let (mut above, mut current): (Option<Row>, Option<Row>) = (None, None);
+    let mut width: Option<usize> = None;
+    let mut count = 0;
+
+    for (y, text) in reader.lines().enumerate() {
+        let text = text?;
+        let (cells, numbers) = parse_row(&Line::new(y + 1, &text), y, width)?;
+        width = Some(cells.len());
+        count += 1;
+
+        let below = Row { y, cells, numbers };
+        if let Some(current) = &current {
+            look_at(
+                above.as_ref(),
+                current,
+                Some(&below),
+                rules,
+                &mut totals,
+                &mut emit,
+            )?;
+        }
+        above = current.take();
+        current = Some(below);
+    }
+
+    match &current {
+        Some(current) => look_at(above.as_ref(), current, None, rules, &mut totals, &mut emit)?,
+        None => return Err(Line::new(count + 1, "").error("a schematic").into()),
+    }
+    Ok(totals)
+}
+
+/// Emits the part numbers and gears of `current`.
+///
+/// Fails with [`gear_error`] at the first gear that overflows, as
+/// `part2::part2` does.
+fn look_at(
+    above: Option<&Row>,
+    current: &Row,
+    below: Option<&Row>,
+    rules: &Rules,
+    totals: &mut Totals,
+    emit: &mut impl FnMut(Event),
+) -> Result<(), ParseError> {
+    let window: Vec<&Row> = [above, Some(current), below]
+        .into_iter()
+        .flatten()
+        .collect();
+
+    for number in &current.numbers {
+        let touches_symbol = window.iter().any(|row| {
+            let end = (number.col_end + 1).min(row.cells.len());
+            (number.col_start.saturating_sub(1)..end)
+                .any(|x| rules.is_symbol(row.cells[x]) && number.touches((x, row.y)))
+        });
+        if touches_symbol {
+            totals.part1 += u64::from(number.value);
+            emit(Event::PartNumber(*number));
+        }
+    }
+
+    for (x, square) in current.cells.iter().enumerate() {
+        if !rules.is_gear(*square) {
+            continue;
+        }
+        let components: Vec<u32> = window
+            .iter()
+            .flat_map(|row| &row.numbers)
+            .filter(|number| number.touches((x, current.y)))
+            .map(|number| number.value)
+            .collect();
+        if components.len() == rules.gear_numbers {
+            let gear = Gear {
+                position: (x, current.y),
+                components,
+            };
+            totals.part2 = gear
+                .ratio()
+                .and_then(|x| totals.part2.checked_add(x))
+                .ok_or_else(|| gear_error(&current.cells, gear.position))?;
+            emit(Event::Gear(gear));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::part1::part_numbers;
+    use crate::part2::gears;
+    use crate::schematic::parse_schematic;
+    use aoc_common::random::Lcg;
+    use aoc_common::read_input;
+
+    /// Streams `input`, checking the events against the in-memory answers.
+    fn compare(input: &str, rules: &Rules) -> Totals {
+        let mut events: Vec<Event> = Vec::new();
+        let totals = stream(input.as_bytes(), rules, |x| events.push(x)).unwrap();
+
+        let schematic = parse_schematic(input).unwrap();
+        let numbers = part_numbers(&schematic, rules);
+        let gears = gears(&schematic, rules);
+
+        let streamed_numbers: Vec<&PartNumber> = events
+            .iter()
+            .filter_map(|x| match x {
+                Event::PartNumber(number) => Some(number),
+                Event::Gear(_) => None,
+            })
+            .collect();
+        let streamed_gears: Vec<&Gear> = events
+            .iter()
+            .filter_map(|x| match x {
+                Event::Gear(gear) => Some(gear),
+                Event::PartNumber(_) => None,
+            })
+            .collect();
+        assert_eq!(streamed_numbers, numbers);
+        assert_eq!(streamed_gears, gears.iter().collect::<Vec<&Gear>>());
+        totals
+    }
+
+    #[test]
+    fn test_examples() {
+        let totals = compare(&read_input("input/example1.txt"), &Rules::default());
+        assert_eq!(
+            totals,
+            Totals {
+                part1: 4361,
+                part2: 467835
+            }
+        );
+
+        let totals = compare(&read_input("input/example1_2.txt"), &Rules::default());
+        assert_eq!(
+            totals,
+            Totals {
+                part1: 925,
+                part2: 6756
+            }
+        );
+    }
+
+    #[test]
+    fn test_variant_rules() {
+        let rules = Rules {
+            symbols: Some(vec!['*', '$']),
+            gear: '$',
+            gear_numbers: 1,
+            ..Rules::default()
+        };
+        compare(&read_input("input/example1.txt"), &rules);
+        compare(&read_input("input/example1_2.txt"), &rules);
+    }
+
+    #[test]
+    fn test_generated() {
+        let mut lcg = Lcg::new(3);
+        let squares = ['.', '.', '.', '.', '1', '2', '5', '7', '9', '*', '#', '+'];
+        let mut input = String::new();
+        for _ in 0..60 {
+            for _ in 0..80 {
+                input.push(squares[lcg.below(squares.len())]);
+            }
+            input.push('\n');
+        }
+
+        let totals = compare(&input, &Rules::default());
+        let schematic = parse_schematic(&input).unwrap();
+        assert_eq!(totals.part1, crate::part1::part1(&schematic));
+        assert_eq!(Ok(totals.part2), crate::part2::part2(&schematic));
+    }
+
+    #[test]
+    fn test_single_row() {
+        let totals = compare("12*3..4", &Rules::default());
+        assert_eq!(
+            totals,
+            Totals {
+                part1: 15,
+                part2: 36
+            }
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = stream("467.\n...*\n..".as_bytes(), &Rules::default(), |_| {}).unwrap_err();
+        let StreamError::Parse(err) = err else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.line, err.expected.as_str()), (3, "a row of 4 squares"));
+
+        let err = stream("".as_bytes(), &Rules::default(), |_| {}).unwrap_err();
+        assert!(matches!(
+            err,
+            StreamError::Parse(ParseError { line: 1, .. })
+        ));
+    }
+}
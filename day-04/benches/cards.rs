@@ -0,0 +1,98 @@
+//! Compares counting matches on cards stored as vectors, the way cards used
+//! to be, against cards stored as bitsets.
+//!
+//! ```sh
+//! cargo bench -p day-04
+//! ```
+
+use aoc_common::random::Lcg;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_04::card::{lines_to_cards, Card};
+
+/// The number of winning and held numbers on a card of the real puzzle.
+const WINNING: usize = 10;
+const HOLDING: usize = 25;
+
+/// A card the way it was stored before the bitset.
+struct VecCard {
+    winning: Vec<u32>,
+    holding: Vec<u32>,
+}
+
+impl VecCard {
+    fn matches(&self) -> usize {
+        self.holding
+            .iter()
+            .filter(|x| self.winning.contains(x))
+            .count()
+    }
+}
+
+/// Generates the numbers of `count` cards, all distinct numbers from 1 to 99
+/// like the real puzzle, from a fixed seed so runs compare.
+fn generate(count: usize) -> Vec<(Vec<u32>, Vec<u32>)> {
+    let mut lcg = Lcg::new(0x2023_1204);
+
+    (0..count)
+        .map(|_| {
+            let mut numbers: Vec<u32> = (1..100).collect();
+            for i in 0..WINNING + HOLDING {
+                let j = i + lcg.below(numbers.len() - i);
+                numbers.swap(i, j);
+            }
+            // Let some of the held numbers come from the winning ones
+            let mut holding = numbers[WINNING..WINNING + HOLDING].to_vec();
+            let shared = lcg.below(6);
+            holding[..shared].copy_from_slice(&numbers[..shared]);
+            (numbers[..WINNING].to_vec(), holding)
+        })
+        .collect()
+}
+
+fn bench_matches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("matches");
+
+    for count in [1_000, 10_000] {
+        let numbers = generate(count);
+        let vec_cards: Vec<VecCard> = numbers
+            .iter()
+            .map(|(winning, holding)| VecCard {
+                winning: winning.clone(),
+                holding: holding.clone(),
+            })
+            .collect();
+        let input: Vec<String> = numbers
+            .iter()
+            .enumerate()
+            .map(|(i, (winning, holding))| {
+                let join = |x: &[u32]| x.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+                let (winning, holding) = (join(winning), join(holding));
+                format!(
+                    "Card {}: {} | {}",
+                    i + 1,
+                    winning.join(" "),
+                    holding.join(" ")
+                )
+            })
+            .collect();
+        let cards: Vec<Card> = lines_to_cards(&input.join("\n")).unwrap();
+        let vec_matches: Vec<usize> = vec_cards.iter().map(|x| x.matches()).collect();
+        let matches: Vec<usize> = cards.iter().map(|x| x.matches()).collect();
+        assert_eq!(
+            vec_matches, matches,
+            "both card layouts should count the same matches"
+        );
+
+        group.bench_with_input(BenchmarkId::new("vec", count), &vec_cards, |b, cards| {
+            b.iter(|| black_box(cards).iter().map(|x| x.matches()).sum::<usize>())
+        });
+        group.bench_with_input(BenchmarkId::new("bitset", count), &cards, |b, cards| {
+            b.iter(|| black_box(cards).iter().map(|x| x.matches()).sum::<usize>())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_matches);
+criterion_main!(benches);
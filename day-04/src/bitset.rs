@@ -0,0 +1,105 @@
+use std::fmt;
+
+/// A set of the numbers below `NumberSet::CAPACITY`, one bit per number.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct NumberSet(u128);
+
+impl NumberSet {
+    /// Every number stored must be below this.
+    pub const CAPACITY: u32 = 128;
+
+    pub fn new() -> NumberSet {
+        NumberSet(0)
+    }
+
+    /// Adds `number` to the set, returning whether it wasn't already in it.
+    ///
+    /// Panics if `number` is not below `CAPACITY`.
+    pub fn insert(&mut self, number: u32) -> bool {
+        assert!(
+            number < NumberSet::CAPACITY,
+            "{} does not fit in a NumberSet",
+            number
+        );
+        let added = !self.contains(number);
+        self.0 |= 1 << number;
+        added
+    }
+
+    pub fn contains(&self, number: u32) -> bool {
+        number < NumberSet::CAPACITY && self.0 & (1 << number) != 0
+    }
+
+    /// Returns the numbers in both sets.
+    pub fn intersection(&self, other: &NumberSet) -> NumberSet {
+        NumberSet(self.0 & other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Iterates over the numbers of the set in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        (0..NumberSet::CAPACITY).filter(|x| self.contains(*x))
+    }
+}
+
+impl FromIterator<u32> for NumberSet {
+    fn from_iter<I: IntoIterator<Item = u32>>(iter: I) -> NumberSet {
+        let mut set = NumberSet::new();
+        for number in iter {
+            set.insert(number);
+        }
+        set
+    }
+}
+
+impl fmt::Debug for NumberSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut set = NumberSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(0));
+        assert!(set.insert(127));
+        assert!(!set.insert(0));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(127));
+        assert!(!set.contains(5));
+        assert!(!set.contains(500));
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit")]
+    fn test_insert_too_large() {
+        NumberSet::new().insert(128);
+    }
+
+    #[test]
+    fn test_intersection() {
+        let winning: NumberSet = [41, 48, 83, 86, 17].into_iter().collect();
+        let holding: NumberSet = [83, 86, 6, 31, 17, 9, 48, 53].into_iter().collect();
+        let both = winning.intersection(&holding);
+        assert_eq!(both.len(), 4);
+        assert_eq!(both.iter().collect::<Vec<u32>>(), vec![17, 48, 83, 86]);
+    }
+
+    #[test]
+    fn test_debug() {
+        let set: NumberSet = [9, 2].into_iter().collect();
+        assert_eq!(format!("{:?}", set), "{2, 9}");
+    }
+}
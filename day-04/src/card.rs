@@ -0,0 +1,175 @@
+use crate::bitset::NumberSet;
+use aoc_common::parse::{lines, Line};
+use aoc_common::ParseError;
+
+#[derive(Debug)]
+pub struct Card {
+    pub index: usize,
+    pub winning: NumberSet,
+    pub holding: NumberSet,
+    /// The line the card was read from, for errors found while solving.
+    pub line: usize,
+    pub text: String,
+}
+
+impl Card {
+    pub fn new(line: &Line, index: usize, winning: NumberSet, holding: NumberSet) -> Self {
+        Self {
+            index,
+            winning,
+            holding,
+            line: line.number,
+            text: line.text.to_string(),
+        }
+    }
+
+    /// Returns how many of the held numbers are winning numbers.
+    pub fn matches(&self) -> usize {
+        self.winning.intersection(&self.holding).len()
+    }
+
+    /// Returns 1 point for the first match, doubled for every other match.
+    /// A card holds at most 128 numbers, so the points always fit.
+    pub fn score(&self) -> u128 {
+        match self.matches() {
+            0 => 0,
+            count => 1 << (count - 1),
+        }
+    }
+
+    /// Returns an error pointing at the `Card <number>` label of the line the
+    /// card was read from.
+    pub fn error<E: Into<String>>(&self, expected: E) -> ParseError {
+        let line = Line::new(self.line, &self.text);
+        let label = self.text.split_once(':').map_or(line.text, |x| x.0);
+        line.error_at(label, expected)
+    }
+}
+
+/// Parses every line of the puzzle input into a card, the cards being
+/// numbered from 1 in order.
+pub fn lines_to_cards(input: &str) -> Result<Vec<Card>, ParseError> {
+    lines(input)
+        .enumerate()
+        .map(|(position, line)| {
+            let card = line_to_card(line)?;
+            if card.index != position + 1 {
+                let label = line.text.split_once(':').map_or(line.text, |x| x.0);
+                let index = label.trim_start_matches("Card").trim();
+                return Err(line.error_at(index, format!("card {}", position + 1)));
+            }
+            Ok(card)
+        })
+        .collect()
+}
+
+/// Parses a line such as `Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53`.
+pub fn line_to_card(line: Line) -> Result<Card, ParseError> {
+    let (label, hand) = line.split_once(line.text, ':')?;
+    let index = match label.strip_prefix("Card") {
+        Some(index) => line.number::<usize>(index)?,
+        None => return Err(line.error_at(label, "`Card <number>`")),
+    };
+
+    let (winning, holding) = line.split_once(hand, '|')?;
+    let winning = number_set(&line, winning)?;
+    let holding = number_set(&line, holding)?;
+
+    Ok(Card::new(&line, index, winning, holding))
+}
+
+/// Parses whitespace separated numbers, which must fit in a `NumberSet`.
+fn number_set(line: &Line, part: &str) -> Result<NumberSet, ParseError> {
+    let mut set = NumberSet::new();
+    for token in part.split_whitespace() {
+        let number: u32 = line.number(token)?;
+        if number >= NumberSet::CAPACITY {
+            let expected = format!("a number below {}", NumberSet::CAPACITY);
+            return Err(line.error_at(token, expected));
+        }
+        set.insert(number);
+    }
+    Ok(set)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Result<Card, ParseError> {
+        line_to_card(Line::new(1, text))
+    }
+
+    #[test]
+    fn test_line_to_card() {
+        let card = parse("Card  12: 41 48 | 83  6").unwrap();
+        assert_eq!(card.index, 12);
+        assert_eq!(card.winning, [41, 48].into_iter().collect());
+        assert_eq!(card.holding, [83, 6].into_iter().collect());
+    }
+
+    #[test]
+    fn test_missing_colon() {
+        let err = parse("Card 1 41 48 | 83 86").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (21, "`:`"));
+    }
+
+    #[test]
+    fn test_invalid_label() {
+        let err = parse("Cart 1: 41 48 | 83 86").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (1, "Cart 1"));
+    }
+
+    #[test]
+    fn test_invalid_index() {
+        let err = parse("Card x: 41 48 | 83 86").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (6, "x"));
+    }
+
+    #[test]
+    fn test_number_too_large() {
+        let err = parse("Card 1: 41 480 | 83 86").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (12, "480"));
+        assert_eq!(err.expected, "a number below 128");
+    }
+
+    #[test]
+    fn test_missing_bar() {
+        let err = parse("Card 1: 41 48 83 86").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (20, "`|`"));
+    }
+
+    #[test]
+    fn test_invalid_number() {
+        let err = parse("Card 1: 41 48 | 83 8b").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (20, "8b"));
+        assert_eq!(err.expected, "a number");
+    }
+
+    #[test]
+    fn test_score() {
+        let all: Vec<String> = (0..128).map(|x| x.to_string()).collect();
+        let all = all.join(" ");
+        let card = parse(&format!("Card 1: {} | {}", all, all)).unwrap();
+        assert_eq!(card.score(), 1 << 127);
+        assert_eq!(parse("Card 1: 1 2 | 3").unwrap().score(), 0);
+    }
+
+    #[test]
+    fn test_card_order() {
+        let err = lines_to_cards("Card 1: 1 | 2\nCard 1: 1 | 2").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 6, "1"));
+        assert_eq!(err.expected, "card 2");
+
+        let err = lines_to_cards("Card 1: 1 | 2\nCard  3: 1 | 2").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (7, "card 2"));
+    }
+
+    #[test]
+    fn test_error_line_number() {
+        let err = lines_to_cards("Card 1: 1 | 2\nCard 2: 1 | -2")
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (2, 13));
+    }
+}
@@ -0,0 +1,188 @@
+use crate::card::Card;
+use crate::part2;
+use aoc_common::ParseError;
+
+/// Why a card scores what it does, and how many copies of it are held.
+#[derive(Debug, PartialEq)]
+pub struct Explanation {
+    pub index: usize,
+    /// The held numbers that are also winning numbers, in increasing order.
+    pub matching: Vec<u32>,
+    pub points: u128,
+    /// The copies held in the end, the original card included.
+    pub copies: u64,
+    /// The earlier cards that won copies of this one, with how many each won.
+    pub won_from: Vec<(usize, u64)>,
+}
+
+impl Explanation {
+    pub fn matches(&self) -> usize {
+        self.matching.len()
+    }
+}
+
+/// Explains every card, in card order.
+///
+/// Fails when the copies held of a card don't fit in 64 bits.
+pub fn explain(cards: &[Card]) -> Result<Vec<Explanation>, ParseError> {
+    let winnings = part2::winnings(cards).map_err(|x| part2::too_many_copies(cards, x))?;
+
+    let explanations = cards
+        .iter()
+        .zip(winnings)
+        .map(|(card, won_from)| Explanation {
+            index: card.index,
+            matching: card.winning.intersection(&card.holding).iter().collect(),
+            points: card.score(),
+            // `winnings` checked every card's copies fit
+            copies: 1 + won_from.iter().map(|x| x.1).sum::<u64>(),
+            won_from,
+        })
+        .collect();
+    Ok(explanations)
+}
+
+/// Renders explanations as an aligned table with a header line.
+///
+/// Where copies were won from is written `<card>×<copies>`.
+pub fn render_text(explanations: &[Explanation]) -> String {
+    let rows: Vec<[String; 6]> = explanations
+        .iter()
+        .map(|x| {
+            let matching: Vec<String> = x.matching.iter().map(|x| x.to_string()).collect();
+            let won_from: Vec<String> = x
+                .won_from
+                .iter()
+                .map(|(index, copies)| format!("{}×{}", index, copies))
+                .collect();
+            [
+                x.index.to_string(),
+                matching.join(" "),
+                x.matches().to_string(),
+                x.points.to_string(),
+                x.copies.to_string(),
+                won_from.join(", "),
+            ]
+        })
+        .collect();
+
+    let header = [
+        "Card", "Matching", "Matches", "Points", "Copies", "Won from",
+    ];
+    let mut widths = header.map(|x| x.chars().count());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    for row in std::iter::once(header.map(String::from)).chain(rows) {
+        let line = format!(
+            "{:>w0$}  {:<w1$}  {:>w2$}  {:>w3$}  {:>w4$}  {}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            row[4],
+            row[5],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+            w4 = widths[4],
+        );
+        table.push_str(line.trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+/// Renders explanations as a JSON array with one object per card.
+pub fn render_json(explanations: &[Explanation]) -> String {
+    let cards: Vec<String> = explanations
+        .iter()
+        .map(|x| {
+            let matching: Vec<String> = x.matching.iter().map(|x| x.to_string()).collect();
+            let won_from: Vec<String> = x
+                .won_from
+                .iter()
+                .map(|(index, copies)| format!("{{\"card\":{},\"copies\":{}}}", index, copies))
+                .collect();
+            format!(
+                "  {{\"card\":{},\"matching\":[{}],\"matches\":{},\"points\":{},\"copies\":{},\"won_from\":[{}]}}",
+                x.index,
+                matching.join(","),
+                x.matches(),
+                x.points,
+                x.copies,
+                won_from.join(",")
+            )
+        })
+        .collect();
+
+    if cards.is_empty() {
+        "[]\n".to_string()
+    } else {
+        format!("[\n{}\n]\n", cards.join(",\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::lines_to_cards;
+    use aoc_common::read_input;
+
+    const EXAMPLE1: &str = "input/example1.txt";
+
+    fn example() -> Vec<Explanation> {
+        explain(&lines_to_cards(&read_input(EXAMPLE1)).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_explain() {
+        let explanations = example();
+        assert_eq!(explanations.len(), 6);
+        assert_eq!(
+            explanations[2],
+            Explanation {
+                index: 3,
+                matching: vec![1, 21],
+                points: 2,
+                copies: 4,
+                won_from: vec![(1, 1), (2, 2)],
+            }
+        );
+        let points: u128 = explanations.iter().map(|x| x.points).sum();
+        let copies: u64 = explanations.iter().map(|x| x.copies).sum();
+        assert_eq!((points, copies), (13, 30));
+    }
+
+    #[test]
+    fn test_render_text() {
+        let text = render_text(&example());
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(
+            lines[0],
+            "Card  Matching     Matches  Points  Copies  Won from"
+        );
+        assert_eq!(lines[1], "   1  17 48 83 86        4       8       1");
+        assert_eq!(
+            lines[5],
+            "   5                     0       0      14  1×1, 3×4, 4×8"
+        );
+    }
+
+    #[test]
+    fn test_render_json() {
+        let json = render_json(&example());
+        let lines: Vec<&str> = json.lines().collect();
+        assert_eq!(lines.len(), 8);
+        assert_eq!(
+            lines[3],
+            "  {\"card\":3,\"matching\":[1,21],\"matches\":2,\"points\":2,\"copies\":4,\"won_from\":[{\"card\":1,\"copies\":1},{\"card\":2,\"copies\":2}]},"
+        );
+        assert_eq!(render_json(&[]), "[]\n");
+    }
+}
@@ -0,0 +1,34 @@
+//! https://adventofcode.com/2023/day/4
+
+use aoc_common::{ParseError, Solution};
+use card::Card;
+
+pub mod bitset;
+pub mod card;
+pub mod explain;
+pub mod part1;
+pub mod part2;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+    type Answer1 = Result<u128, ParseError>;
+    type Answer2 = Result<u64, ParseError>;
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        card::lines_to_cards(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<u128, ParseError> {
+        part1::part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<u64, ParseError> {
+        part2::part2(input)
+    }
+}
@@ -0,0 +1,83 @@
+use crate::card::Card;
+use aoc_common::ParseError;
+
+/// Fails when the points add up past 128 bits.
+pub fn part1(cards: &[Card]) -> Result<u128, ParseError> {
+    sum_cards(cards)
+}
+
+fn sum_cards(cards: &[Card]) -> Result<u128, ParseError> {
+    let mut sum: u128 = 0;
+    for card in cards.iter() {
+        sum = sum
+            .checked_add(card.score())
+            .ok_or_else(|| card.error("points that fit in 128 bits"))?;
+    }
+    Ok(sum)
+}
+
+// test block
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{line_to_card, lines_to_cards};
+    use aoc_common::parse::Line;
+    use aoc_common::read_input;
+
+    const EXAMPLE1: &str = "input/example1.txt";
+
+    #[test]
+    fn test_part1() {
+        let file = read_input(EXAMPLE1);
+        assert_eq!(part1(&lines_to_cards(&file).unwrap()), Ok(13));
+    }
+
+    #[test]
+    fn test_card1() {
+        let file = read_input(EXAMPLE1);
+        let line = file.lines().next().unwrap();
+        let card = line_to_card(Line::new(1, line)).unwrap();
+        let winning = vec![41, 48, 83, 86, 17];
+        let holding = vec![83, 86, 6, 31, 17, 9, 48, 53];
+        assert_eq!(card.index, 1);
+        assert_eq!(card.winning, winning.into_iter().collect());
+        assert_eq!(card.holding, holding.into_iter().collect());
+        assert_eq!(card.score(), 8);
+    }
+
+    #[test]
+    fn test_all_card() {
+        let file = read_input(EXAMPLE1);
+        let cards: Vec<Card> = lines_to_cards(&file).unwrap();
+        let card_values: Vec<u128> = vec![8, 2, 2, 1, 0, 0];
+
+        for (i, card) in cards.iter().enumerate() {
+            assert_eq!(card.score(), card_values[i]);
+        }
+    }
+
+    #[test]
+    fn test_full_hand() {
+        let file = read_input(EXAMPLE1);
+        let cards: Vec<Card> = lines_to_cards(&file).unwrap();
+        let hand = sum_cards(&cards);
+
+        assert_eq!(hand, Ok(13));
+    }
+
+    #[test]
+    fn test_points_overflow() {
+        let all: Vec<String> = (0..128).map(|x| x.to_string()).collect();
+        let all = all.join(" ");
+        let input: Vec<String> = (1..=2)
+            .map(|x| format!("Card {}: {} | {}", x, all, all))
+            .collect();
+        let cards = lines_to_cards(&input.join("\n")).unwrap();
+        assert_eq!(part1(&cards[..1]), Ok(1 << 127));
+
+        let err = part1(&cards).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 1, "Card 2"));
+        assert_eq!(err.text, input[1]);
+        assert_eq!(err.expected, "points that fit in 128 bits");
+    }
+}
@@ -0,0 +1,125 @@
+use crate::card::Card;
+use aoc_common::ParseError;
+
+/// Returns how many scratchcards are held once every won copy is scratched.
+///
+/// Fails when the copies held don't fit in 64 bits.
+pub fn part2(cards: &[Card]) -> Result<u64, ParseError> {
+    let copies = copies(cards).map_err(|x| too_many_copies(cards, x))?;
+    copies
+        .iter()
+        .try_fold(0u64, |sum, x| sum.checked_add(*x))
+        .ok_or_else(|| too_many_copies(cards, cards.len() - 1))
+}
+
+/// Returns how many copies of every card end up held, in card order.
+///
+/// Each card starts with a single copy. A card with `n` matching numbers wins
+/// a copy of each of the `n` cards that follow it, once for every copy of it
+/// held, and copies are never won of cards past the end of the table.
+///
+/// Fails with the position of the first card whose copies don't fit in 64
+/// bits.
+pub fn copies(cards: &[Card]) -> Result<Vec<u64>, usize> {
+    winnings(cards)?
+        .iter()
+        .enumerate()
+        .map(|(position, won)| held(won).ok_or(position))
+        .collect()
+}
+
+/// Returns, for every card in order, which earlier cards won copies of it and
+/// how many copies each of them won, in card order.
+///
+/// Fails with the position of the first card whose copies don't fit in 64
+/// bits.
+pub fn winnings(cards: &[Card]) -> Result<Vec<Vec<(usize, u64)>>, usize> {
+    let mut winnings: Vec<Vec<(usize, u64)>> = vec![Vec::new(); cards.len()];
+
+    for (position, card) in cards.iter().enumerate() {
+        let held = held(&winnings[position]).ok_or(position)?;
+        let end = cards.len().min(position + 1 + card.matches());
+        for won in &mut winnings[position + 1..end] {
+            won.push((card.index, held));
+        }
+    }
+
+    Ok(winnings)
+}
+
+/// The original card and every copy of it won, `None` if that overflows.
+fn held(won: &[(usize, u64)]) -> Option<u64> {
+    won.iter().try_fold(1u64, |sum, x| sum.checked_add(x.1))
+}
+
+/// The error for the copies of the card at `position` not fitting in 64 bits.
+pub(crate) fn too_many_copies(cards: &[Card], position: usize) -> ParseError {
+    cards[position].error("copies that fit in 64 bits")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::lines_to_cards;
+    use aoc_common::read_input;
+
+    const EXAMPLE1: &str = "input/example1.txt";
+
+    #[test]
+    fn test_matches() {
+        let cards = lines_to_cards(&read_input(EXAMPLE1)).unwrap();
+        let matches: Vec<usize> = cards.iter().map(|x| x.matches()).collect();
+        assert_eq!(matches, vec![4, 2, 2, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_copies() {
+        let cards = lines_to_cards(&read_input(EXAMPLE1)).unwrap();
+        assert_eq!(copies(&cards), Ok(vec![1, 2, 4, 8, 14, 1]));
+    }
+
+    #[test]
+    fn test_winnings() {
+        let cards = lines_to_cards(&read_input(EXAMPLE1)).unwrap();
+        let winnings = winnings(&cards).unwrap();
+        assert_eq!(winnings[0], vec![]);
+        assert_eq!(winnings[2], vec![(1, 1), (2, 2)]);
+        assert_eq!(winnings[4], vec![(1, 1), (3, 4), (4, 8)]);
+        assert_eq!(winnings[5], vec![]);
+    }
+
+    #[test]
+    fn test_example_answer() {
+        let cards = lines_to_cards(&read_input(EXAMPLE1)).unwrap();
+        assert_eq!(part2(&cards), Ok(30));
+    }
+
+    #[test]
+    fn test_copies_stop_at_last_card() {
+        let cards = lines_to_cards("Card 1: 1 2 | 1 2\nCard 2: 3 4 5 | 3 4 5").unwrap();
+        assert_eq!(copies(&cards), Ok(vec![1, 2]));
+        assert_eq!(part2(&cards), Ok(3));
+    }
+
+    #[test]
+    fn test_copies_overflow() {
+        // Every card wins a copy of every card after it, doubling the copies
+        let numbers: Vec<String> = (0..70).map(|x| x.to_string()).collect();
+        let numbers = numbers.join(" ");
+        let input: Vec<String> = (1..=66)
+            .map(|x| format!("Card {}: {} | {}", x, numbers, numbers))
+            .collect();
+        let cards = lines_to_cards(&input.join("\n")).unwrap();
+
+        assert_eq!(copies(&cards[..64]).unwrap()[63], 1 << 63);
+        assert_eq!(part2(&cards[..64]), Ok(u64::MAX));
+        assert_eq!(copies(&cards), Err(64));
+
+        let err = part2(&cards).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.found.as_str()),
+            (65, 1, "Card 65")
+        );
+        assert_eq!(err.text, input[64]);
+    }
+}
@@ -0,0 +1,965 @@
+use crate::rule::{self, Rule, RuleError};
+use aoc_common::parse::{blocks, end_of_input, Line};
+use aoc_common::ParseError;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::ops::Range;
+
+/// The parsed puzzle input.
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Seeds,
+    pub maps: Vec<Map>,
+    /// The line number and text of the seeds line, for errors about seed
+    /// ranges that only part 2 reads.
+    pub seeds_line: (usize, String),
+}
+
+impl Almanac {
+    /// The seeds read as start and length pairs, failing at the first seed
+    /// that does not make a range.
+    pub fn seed_ranges(&self) -> Result<Vec<Range<u64>>, ParseError> {
+        let (number, text) = &self.seeds_line;
+        let line = Line::new(*number, text);
+        self.seeds.get_seeds_range().map_err(|(index, expected)| {
+            let parts = text.split_once(':').map_or("", |x| x.1);
+            match parts.split_whitespace().nth(index) {
+                Some(part) => line.error_at(part, expected),
+                None => line.error_after(line.text, expected),
+            }
+        })
+    }
+
+    /// Returns the map converting `source` straight into `destination`.
+    pub fn map(&self, source: &str, destination: &str) -> Option<&Map> {
+        self.maps
+            .iter()
+            .find(|x| x.source == source && x.destination == destination)
+    }
+
+    /// Finds the shortest sequence of maps converting `from` into `to`, or
+    /// `None` when no maps lead there. Any category can be a start or an end.
+    pub fn chain(&self, from: &str, to: &str) -> Option<Chain<'_>> {
+        // Breadth first, remembering the map used to first reach a category
+        let mut reached_by: HashMap<&str, Option<&Map>> = HashMap::from([(from, None)]);
+        let mut queue: VecDeque<&str> = VecDeque::from([from]);
+
+        while let Some(category) = queue.pop_front() {
+            if category == to {
+                break;
+            }
+            for map in self.maps.iter().filter(|x| x.source == category) {
+                if !reached_by.contains_key(map.destination.as_str()) {
+                    reached_by.insert(&map.destination, Some(map));
+                    queue.push_back(&map.destination);
+                }
+            }
+        }
+
+        let mut maps: Vec<&Map> = Vec::new();
+        let mut category = to;
+        while let Some(map) = *reached_by.get(category)? {
+            maps.push(map);
+            category = &map.source;
+        }
+        maps.reverse();
+
+        Some(Chain { maps })
+    }
+
+    /// Composes the chain from `from` to `to` into a single map, eg a
+    /// `seed-to-location` map answering any query in one lookup.
+    pub fn flatten(&self, from: &str, to: &str) -> Option<Map> {
+        let chain = self.chain(from, to)?;
+        let identity = Map::new(from, from, Vec::new());
+        Some(
+            chain
+                .maps
+                .iter()
+                .fold(identity, |map, next| map.compose(next)),
+        )
+    }
+}
+
+/// A sequence of maps, each converting into the category the next one reads.
+#[derive(Debug)]
+pub struct Chain<'a> {
+    pub maps: Vec<&'a Map>,
+}
+
+impl Chain<'_> {
+    /// Sends a single value through every map of the chain.
+    pub fn compute(&self, input: u64) -> u64 {
+        self.maps
+            .iter()
+            .fold(input, |value, map| map.compute(value))
+    }
+
+    /// Sends whole ranges of values through every map of the chain.
+    pub fn compute_ranges(&self, input: &[Range<u64>]) -> Vec<Range<u64>> {
+        let mut ranges = input.to_vec();
+        for map in &self.maps {
+            ranges = map.compute_ranges(&ranges);
+        }
+        ranges
+    }
+
+    /// Returns every value that the chain turns into `output`, sorted, eg
+    /// the seeds that end up at a location.
+    pub fn invert(&self, output: u64) -> Vec<u64> {
+        let mut values = vec![output];
+        for map in self.maps.iter().rev() {
+            values = values.iter().flat_map(|x| map.invert(*x)).collect();
+            values.sort();
+            values.dedup();
+        }
+        values
+    }
+
+    /// Returns the ranges of every value the chain turns into one of `output`.
+    pub fn invert_ranges(&self, output: &[Range<u64>]) -> Vec<Range<u64>> {
+        let mut ranges = output.to_vec();
+        for map in self.maps.iter().rev() {
+            ranges = map.invert_ranges(&ranges);
+        }
+        ranges
+    }
+}
+
+#[derive(Debug)]
+pub struct Seeds {
+    pub seeds: Vec<u64>,
+}
+
+impl Seeds {
+    pub fn new(seeds: Vec<u64>) -> Seeds {
+        Seeds { seeds }
+    }
+    /// Parses a `seeds: 79 14 55 13` line.
+    pub fn from_line(line: &Line) -> Result<Seeds, ParseError> {
+        let (label, seeds) = line.split_once(line.text, ':')?;
+        if label != "seeds" {
+            return Err(line.error_at(label, "`seeds`"));
+        }
+        let seeds: Vec<u64> = line.numbers(seeds)?;
+        if seeds.is_empty() {
+            return Err(line.error_after(line.text, "a seed"));
+        }
+
+        Ok(Seeds::new(seeds))
+    }
+    /// The seeds read as start and length pairs, as part 2 reads them.
+    ///
+    /// Fails with the index of the first seed that does not make a range and
+    /// what it should have been, the index being the number of seeds when the
+    /// last range has no length.
+    pub fn get_seeds_range(&self) -> Result<Vec<Range<u64>>, (usize, &'static str)> {
+        if self.seeds.is_empty() {
+            return Err((0, "a seed"));
+        }
+        let mut seeds_range: Vec<Range<u64>> = Vec::new();
+
+        // Get the seeds vec as pairs
+        let seeds_pairs = self.seeds.chunks(2);
+
+        for (idx, pair) in seeds_pairs.enumerate() {
+            let &[start, range] = pair else {
+                return Err((self.seeds.len(), "the length of the last seed range"));
+            };
+            if range == 0 {
+                return Err((idx * 2 + 1, "a seed range length above zero"));
+            }
+            let Some(end) = start.checked_add(range) else {
+                return Err((idx * 2 + 1, "a range within 64 bits"));
+            };
+            seeds_range.push(start..end);
+        }
+        Ok(seeds_range)
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    pub source: String,
+    pub destination: String,
+    pub rules: Vec<Rule>,
+}
+
+impl Map {
+    pub fn new(source: &str, destination: &str, rules: Vec<Rule>) -> Map {
+        Map {
+            source: source.to_string(),
+            destination: destination.to_string(),
+            rules,
+        }
+    }
+    /// Parses a `<source>-to-<destination> map:` header followed by its rules,
+    /// which must pass `validate`.
+    pub fn from_lines(lines: &[Line]) -> Result<Map, ParseError> {
+        let header = &lines[0];
+        let name = match header.text.strip_suffix(" map:") {
+            Some(name) => name.trim(),
+            None => return Err(header.error_at(header.text, "`<name> map:`")),
+        };
+        let (source, destination) = match name.split_once("-to-") {
+            Some((source, destination)) if !source.is_empty() && !destination.is_empty() => {
+                (source, destination)
+            }
+            _ => return Err(header.error_at(name, "a map name such as `seed-to-soil`")),
+        };
+
+        let rows = &lines[1..];
+        let mut rules: Vec<Rule> = Vec::new();
+        for line in rows {
+            let row: Vec<u64> = line.numbers(line.text)?;
+            if row.len() != 3 {
+                return Err(line.error_at(line.text, "three numbers"));
+            }
+            rules.push(Rule::new(row[0], row[1], row[2]));
+        }
+
+        if let Some(error) = rule::validate(&rules).first() {
+            let (index, expected) = match *error {
+                RuleError::Overflow { rule } => (rule, "a range within 64 bits".to_string()),
+                RuleError::Empty { rule } => (rule, "a rule with a length above zero".to_string()),
+                RuleError::Overlap { rule, other } => (
+                    rule,
+                    format!("a source range clear of line {}", rows[other].number),
+                ),
+            };
+            let line = &rows[index];
+            return Err(line.error_at(line.text, expected));
+        }
+
+        Ok(Map::new(source, destination, rules))
+    }
+
+    /// Checks the rules of the map, see `rule::validate`.
+    pub fn validate(&self) -> Vec<RuleError> {
+        rule::validate(&self.rules)
+    }
+
+    /// Returns the same map with its rules sorted and every gap between them
+    /// filled, see `rule::normalise`.
+    pub fn normalise(&self) -> Map {
+        Map::new(
+            &self.source,
+            &self.destination,
+            rule::normalise(&self.rules),
+        )
+    }
+
+    pub fn compute(&self, input: u64) -> u64 {
+        self.rules
+            .iter()
+            .find_map(|rule| rule.apply(input))
+            .unwrap_or(input)
+    }
+
+    /// Maps a set of ranges at once, splitting every range where it crosses
+    /// the edge of a rule so each piece can be shifted as a whole.
+    ///
+    /// The returned ranges are sorted and merged, so the work done depends on
+    /// the number of rules rather than on the number of values in the ranges.
+    pub fn compute_ranges(&self, input: &[Range<u64>]) -> Vec<Range<u64>> {
+        let mut mapped: Vec<Range<u64>> = Vec::new();
+        let mut unmapped: Vec<Range<u64>> =
+            input.iter().filter(|x| !x.is_empty()).cloned().collect();
+
+        for rule in &self.rules {
+            let source = rule.source();
+
+            let mut remaining: Vec<Range<u64>> = Vec::new();
+            for range in unmapped {
+                let (inside, outside) = split_range(&range, &source);
+                if let Some(inside) = inside {
+                    mapped.push(
+                        rule.dst + (inside.start - rule.src)..rule.dst + (inside.end - rule.src),
+                    );
+                }
+                remaining.extend(outside);
+            }
+            unmapped = remaining;
+        }
+
+        // Whatever no rule covers keeps its value
+        mapped.extend(unmapped);
+        merge_ranges(mapped)
+    }
+
+    /// Returns every value `compute` turns into `output`, sorted.
+    ///
+    /// That is usually a single value, but a rule can send a value onto one
+    /// that is left unmapped, and a value can be reached by nothing at all.
+    pub fn invert(&self, output: u64) -> Vec<u64> {
+        let mut inputs: Vec<u64> = self
+            .rules
+            .iter()
+            .filter_map(|rule| rule.unapply(output))
+            .collect();
+
+        // A value no rule reads from maps onto itself
+        if !self
+            .rules
+            .iter()
+            .any(|rule| rule.source().contains(&output))
+        {
+            inputs.push(output);
+        }
+
+        inputs.sort();
+        inputs.dedup();
+        inputs
+    }
+
+    /// Returns the ranges of every value `compute` turns into one of `output`,
+    /// sorted and merged.
+    pub fn invert_ranges(&self, output: &[Range<u64>]) -> Vec<Range<u64>> {
+        let mut inputs: Vec<Range<u64>> = Vec::new();
+        let mut unmapped: Vec<Range<u64>> =
+            output.iter().filter(|x| !x.is_empty()).cloned().collect();
+
+        for rule in &self.rules {
+            let destination = rule.destination();
+
+            for range in output {
+                if let (Some(inside), _) = split_range(range, &destination) {
+                    inputs.push(
+                        rule.src + (inside.start - rule.dst)..rule.src + (inside.end - rule.dst),
+                    );
+                }
+            }
+
+            // Only values outside every source range can map onto themselves
+            unmapped = unmapped
+                .iter()
+                .flat_map(|range| split_range(range, &rule.source()).1)
+                .collect();
+        }
+
+        inputs.extend(unmapped);
+        merge_ranges(inputs)
+    }
+
+    /// Looks `input` up with a binary search, for maps whose rules are sorted
+    /// by source, such as the ones `compose` and `normalise` return.
+    pub fn compute_sorted(&self, input: u64) -> u64 {
+        // The last rule starting at or before the input is the only candidate
+        let index = self.rules.partition_point(|rule| rule.src <= input);
+        if index > 0 {
+            if let Some(output) = self.rules[index - 1].apply(input) {
+                return output;
+            }
+        }
+        input
+    }
+
+    /// Combines this map with one reading what it writes into a single map
+    /// doing both, eg `seed-to-soil` and `soil-to-fertilizer` into
+    /// `seed-to-fertilizer`.
+    ///
+    /// Rules are split wherever a rule of one map crosses an edge of the
+    /// other. The result is sorted by source, with touching rules merged and
+    /// the rules that end up mapping values onto themselves left out.
+    pub fn compose(&self, next: &Map) -> Map {
+        let mut rules: Vec<Rule> = Vec::new();
+        let next_rules = rule::normalise(&next.rules);
+
+        for rule in rule::normalise(&self.rules) {
+            let image = rule.destination();
+
+            for next_rule in &next_rules {
+                if let (Some(inside), _) = split_range(&image, &next_rule.source()) {
+                    let composed = Rule::new(
+                        next_rule.dst + (inside.start - next_rule.src),
+                        rule.src + (inside.start - rule.dst),
+                        inside.end - inside.start,
+                    );
+                    if !composed.is_identity() {
+                        rules.push(composed);
+                    }
+                }
+            }
+        }
+
+        rules.sort_by_key(|rule| rule.src);
+        let mut merged: Vec<Rule> = Vec::new();
+        for rule in rules {
+            match merged.last_mut() {
+                Some(last)
+                    if last.src + last.len == rule.src && last.dst + last.len == rule.dst =>
+                {
+                    last.len += rule.len
+                }
+                _ => merged.push(rule),
+            }
+        }
+
+        Map::new(&self.source, &next.destination, merged)
+    }
+}
+
+/// Writes the map back out the way the almanac lists it.
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}-to-{} map:", self.source, self.destination)?;
+        for rule in &self.rules {
+            writeln!(f, "{}", rule)?;
+        }
+        Ok(())
+    }
+}
+
+/// Splits `range` into the part inside `other` and the parts either side of it.
+fn split_range(range: &Range<u64>, other: &Range<u64>) -> (Option<Range<u64>>, Vec<Range<u64>>) {
+    let start = range.start.max(other.start);
+    let end = range.end.min(other.end);
+    if start >= end {
+        return (None, vec![range.clone()]);
+    }
+
+    let mut outside: Vec<Range<u64>> = Vec::new();
+    if range.start < start {
+        outside.push(range.start..start);
+    }
+    if end < range.end {
+        outside.push(end..range.end);
+    }
+    (Some(start..end), outside)
+}
+
+/// Sorts ranges and merges the ones that overlap or touch.
+fn merge_ranges(mut ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+    ranges.sort_by_key(|x| x.start);
+
+    let mut merged: Vec<Range<u64>> = Vec::new();
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// The category the seeds are listed in.
+pub const SEED: &str = "seed";
+/// The category both parts look for the lowest value of.
+pub const LOCATION: &str = "location";
+
+/// Parses the puzzle input into its seeds and maps.
+///
+/// The maps can come in any order and name any categories, as long as they
+/// lead from seeds to locations.
+pub fn extract_data(input: &str) -> Result<Almanac, ParseError> {
+    let mut blocks = blocks(input).into_iter();
+
+    let (seeds, seeds_line) = match blocks.next() {
+        Some(block) => {
+            if let Some(extra) = block.get(1) {
+                return Err(extra.error("a blank line after the seeds"));
+            }
+            let line = &block[0];
+            (
+                Seeds::from_line(line)?,
+                (line.number, line.text.to_string()),
+            )
+        }
+        None => return Err(end_of_input(input).error("a `seeds:` line")),
+    };
+
+    let mut maps: Vec<Map> = Vec::new();
+    for block in blocks {
+        let map = Map::from_lines(&block)?;
+        let duplicate = maps
+            .iter()
+            .any(|x| x.source == map.source && x.destination == map.destination);
+        if duplicate {
+            let header = &block[0];
+            return Err(header.error_at(header.text, "a map not already listed"));
+        }
+        maps.push(map);
+    }
+
+    let almanac = Almanac {
+        seeds,
+        maps,
+        seeds_line,
+    };
+    if almanac.chain(SEED, LOCATION).is_none() {
+        let expected = format!("maps leading from {} to {}", SEED, LOCATION);
+        return Err(end_of_input(input).error(expected));
+    }
+
+    Ok(almanac)
+}
+
+#[cfg(test)]
+// A single range is a set of one range here, not a misspelt `Vec<u64>`
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+    use aoc_common::parse::lines;
+    use aoc_common::read_input;
+
+    const EXAMPLE: &str = "input/example.txt";
+
+    /// Seed 79 should equal Soil 81
+    #[test]
+    fn test_seed_to_soil_79_eq_81() {
+        let input = read_input(EXAMPLE);
+        let almanac = extract_data(&input).unwrap();
+        let seeds_to_soil_map = almanac.map("seed", "soil").unwrap();
+
+        assert_eq!(seeds_to_soil_map.compute(79), 81)
+    }
+
+    /// Seed 14 should equal Soil 14
+    #[test]
+    fn test_seed_to_soil_14_eq_14() {
+        let input = read_input(EXAMPLE);
+        let almanac = extract_data(&input).unwrap();
+        let seeds_to_soil_map = almanac.map("seed", "soil").unwrap();
+
+        assert_eq!(seeds_to_soil_map.compute(14), 14)
+    }
+
+    /// Seed 55 should equal Soil 57
+    #[test]
+    fn test_seed_to_soil_55_eq_57() {
+        let input = read_input(EXAMPLE);
+        let almanac = extract_data(&input).unwrap();
+        let seeds_to_soil_map = almanac.map("seed", "soil").unwrap();
+
+        assert_eq!(seeds_to_soil_map.compute(55), 57)
+    }
+
+    /// Seed 13 should equal Soil 13
+    #[test]
+    fn test_seed_to_soil_13_eq_13() {
+        let input = read_input(EXAMPLE);
+        let almanac = extract_data(&input).unwrap();
+        let seeds_to_soil_map = almanac.map("seed", "soil").unwrap();
+
+        assert_eq!(seeds_to_soil_map.compute(13), 13)
+    }
+
+    #[test]
+    fn test_seed_79_to_location_82() {
+        let input = read_input(EXAMPLE);
+        let almanac = extract_data(&input).unwrap();
+        let chain = almanac.chain(SEED, LOCATION).unwrap();
+        assert_eq!(chain.compute(79), 82)
+    }
+
+    #[test]
+    fn test_seed_14_to_location_43() {
+        let input = read_input(EXAMPLE);
+        let almanac = extract_data(&input).unwrap();
+        let chain = almanac.chain(SEED, LOCATION).unwrap();
+        assert_eq!(chain.compute(14), 43)
+    }
+
+    #[test]
+    fn test_seed_55_to_location_86() {
+        let input = read_input(EXAMPLE);
+        let almanac = extract_data(&input).unwrap();
+        let chain = almanac.chain(SEED, LOCATION).unwrap();
+        assert_eq!(chain.compute(55), 86)
+    }
+
+    #[test]
+    fn test_seed_13_to_location_35() {
+        let input = read_input(EXAMPLE);
+        let almanac = extract_data(&input).unwrap();
+        let chain = almanac.chain(SEED, LOCATION).unwrap();
+        assert_eq!(chain.compute(13), 35)
+    }
+
+    #[test]
+    fn test_get_seed_range() {
+        let input = read_input(EXAMPLE);
+        let seeds = extract_data(&input).unwrap().seeds;
+        let seed_ranges: Vec<Range<u64>> = seeds.get_seeds_range().unwrap();
+        let mut seeds_vec: Vec<u64> = Vec::new();
+
+        for seed_range in seed_ranges {
+            for s in seed_range {
+                seeds_vec.push(s)
+            }
+        }
+
+        // A vector containing 79 to 92, and 55 to 67
+        let known_range = vec![
+            79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 55, 56, 57, 58, 59, 60, 61, 62,
+            63, 64, 65, 66, 67,
+        ];
+
+        assert_eq!(seeds_vec, known_range)
+    }
+
+    fn seed_to_soil() -> Map {
+        let input = read_input(EXAMPLE);
+        let mut maps = extract_data(&input).unwrap().maps;
+        maps.remove(0)
+    }
+
+    #[test]
+    fn test_compute_ranges_inside_row() {
+        assert_eq!(seed_to_soil().compute_ranges(&[79..93]), vec![81..95]);
+    }
+
+    #[test]
+    fn test_compute_ranges_splits_at_row_edges() {
+        let map = seed_to_soil();
+        assert_eq!(map.compute_ranges(&[45..60]), vec![45..50, 52..62]);
+        // 96..98 is shifted up by 2, 98..100 down by 48 and 100.. is unmapped
+        assert_eq!(map.compute_ranges(&[96..102]), vec![50..52, 98..102]);
+    }
+
+    #[test]
+    fn test_compute_ranges_merges() {
+        let map = seed_to_soil();
+        assert_eq!(map.compute_ranges(&[0..10, 5..20, 20..25]), vec![0..25]);
+        assert_eq!(map.compute_ranges(&[3..3]), vec![]);
+    }
+
+    /// Every seed of every range must land in the ranges the engine returns,
+    /// and every location the engine returns must come from some seed.
+    #[test]
+    fn test_cascade_ranges_match_brute_force() {
+        let input = read_input(EXAMPLE);
+        let almanac = extract_data(&input).unwrap();
+        let chain = almanac.chain(SEED, LOCATION).unwrap();
+
+        for seed_range in almanac.seed_ranges().unwrap() {
+            let mut expected: Vec<u64> =
+                seed_range.clone().map(|seed| chain.compute(seed)).collect();
+            expected.sort();
+            expected.dedup();
+
+            let actual: Vec<u64> = chain
+                .compute_ranges(&[seed_range])
+                .into_iter()
+                .flatten()
+                .collect();
+
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_invert() {
+        let map = seed_to_soil();
+        assert_eq!(map.invert(81), vec![79]);
+        assert_eq!(map.invert(14), vec![14]);
+        // 50 is read by the second row, so only 98 ends up there
+        assert_eq!(map.invert(50), vec![98]);
+        assert_eq!(map.invert(98), vec![96]);
+        assert_eq!(map.invert(99), vec![97]);
+        assert_eq!(map.invert(100), vec![100]);
+
+        // 0..5 is sent onto 10..15, so nothing is left at 2 and 12 has two
+        let map = Map::new("a", "b", vec![Rule::new(10, 0, 5)]);
+        assert_eq!(map.invert(2), vec![]);
+        assert_eq!(map.invert(12), vec![2, 12]);
+        assert_eq!(map.invert_ranges(&[0..15]), vec![0..15]);
+        assert_eq!(map.invert_ranges(&[3..5]), vec![]);
+    }
+
+    #[test]
+    fn test_invert_ranges() {
+        let map = seed_to_soil();
+        assert_eq!(map.invert_ranges(&[81..95]), vec![79..93]);
+        assert_eq!(map.invert_ranges(&[45..55]), vec![45..53, 98..100]);
+    }
+
+    #[test]
+    fn test_which_seed_yields_location_35() {
+        let input = read_input(EXAMPLE);
+        let almanac = extract_data(&input).unwrap();
+        let chain = almanac.chain(SEED, LOCATION).unwrap();
+
+        assert!(chain.invert(35).contains(&13));
+        assert!(chain.invert(46).contains(&82));
+        let seeds = chain.invert_ranges(&[35..36]);
+        assert!(seeds.iter().any(|x| x.contains(&13)));
+    }
+
+    /// Every seed found by inverting a location must map back onto it, and
+    /// every seed must be found again from the location it maps onto.
+    #[test]
+    fn test_forward_inverse_identity() {
+        let input = read_input(EXAMPLE);
+        let almanac = extract_data(&input).unwrap();
+        let chain = almanac.chain(SEED, LOCATION).unwrap();
+
+        for location in 0..120 {
+            for seed in chain.invert(location) {
+                assert_eq!(chain.compute(seed), location);
+            }
+
+            let mut expected: Vec<u64> = Vec::new();
+            for range in chain.invert_ranges(&[location..location + 1]) {
+                expected.extend(range);
+            }
+            expected.sort();
+            assert_eq!(chain.invert(location), expected);
+        }
+
+        for seed in 0..120 {
+            assert!(chain.invert(chain.compute(seed)).contains(&seed));
+        }
+    }
+
+    #[test]
+    fn test_compose() {
+        let first = Map::new("a", "b", vec![Rule::new(10, 0, 5)]);
+        let second = Map::new("b", "c", vec![Rule::new(100, 12, 10)]);
+        let composed = first.compose(&second);
+
+        assert_eq!(
+            (composed.source.as_str(), composed.destination.as_str()),
+            ("a", "c")
+        );
+        // 0..2 lands on 10..12 and stays, 2..5 lands on 12..15 and moves on to
+        // 100..103, and 12..22 is only moved by the second map
+        assert_eq!(
+            composed.rules,
+            vec![
+                Rule::new(10, 0, 2),
+                Rule::new(100, 2, 3),
+                Rule::new(100, 12, 10)
+            ]
+        );
+        for input in 0..30 {
+            assert_eq!(
+                composed.compute(input),
+                second.compute(first.compute(input))
+            );
+        }
+    }
+
+    #[test]
+    fn test_compose_drops_identity_rows() {
+        let there = Map::new("a", "b", vec![Rule::new(10, 0, 5), Rule::new(0, 10, 5)]);
+        let back = Map::new("b", "c", vec![Rule::new(0, 10, 5), Rule::new(10, 0, 5)]);
+        assert!(there.compose(&back).rules.is_empty());
+    }
+
+    #[test]
+    fn test_flatten_matches_chain() {
+        let input = read_input(EXAMPLE);
+        let almanac = extract_data(&input).unwrap();
+        let chain = almanac.chain(SEED, LOCATION).unwrap();
+        let flat = almanac.flatten(SEED, LOCATION).unwrap();
+
+        assert_eq!(
+            (flat.source.as_str(), flat.destination.as_str()),
+            (SEED, LOCATION)
+        );
+        assert!(flat
+            .rules
+            .windows(2)
+            .all(|x| x[0].src + x[0].len <= x[1].src));
+        for seed in 0..200 {
+            assert_eq!(flat.compute(seed), chain.compute(seed));
+            assert_eq!(flat.compute_sorted(seed), chain.compute(seed));
+        }
+        assert_eq!(
+            flat.compute_ranges(&[79..93, 55..68]),
+            chain.compute_ranges(&[79..93, 55..68])
+        );
+    }
+
+    #[test]
+    fn test_flatten_empty_chain() {
+        let input = read_input(EXAMPLE);
+        let almanac = extract_data(&input).unwrap();
+        let flat = almanac.flatten("soil", "soil").unwrap();
+        assert!(flat.rules.is_empty());
+        assert_eq!(flat.compute(42), 42);
+        assert!(almanac.flatten("location", "seed").is_none());
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let input = read_input(EXAMPLE);
+        let almanac = extract_data(&input).unwrap();
+        let flat = almanac.flatten(SEED, LOCATION).unwrap();
+
+        let text = flat.to_string();
+        assert!(text.starts_with("seed-to-location map:\n"));
+        let lines: Vec<Line> = lines(&text).collect();
+        let parsed = Map::from_lines(&lines).unwrap();
+        assert_eq!(parsed.rules, flat.rules);
+    }
+
+    #[test]
+    fn test_invalid_seed() {
+        let err = extract_data("seeds: 79 1x").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 11));
+        assert_eq!(err.found, "1x");
+    }
+
+    /// Parses seeds that part 1 can read but part 2 cannot.
+    fn seeds_only(seeds: &str) -> Almanac {
+        extract_data(&format!("{}\n\nseed-to-location map:\n0 0 1", seeds)).unwrap()
+    }
+
+    #[test]
+    fn test_odd_seed_count() {
+        let almanac = seeds_only("seeds: 79 14 55");
+        assert_eq!(almanac.seeds.seeds, vec![79, 14, 55]);
+        let err = almanac.seed_ranges().unwrap_err();
+        assert_eq!((err.line, err.column), (1, 16));
+        assert_eq!(err.expected, "the length of the last seed range");
+    }
+
+    #[test]
+    fn test_empty_seed_range() {
+        let almanac = seeds_only("seeds: 79 14 5 0");
+        assert_eq!(almanac.seeds.seeds, vec![79, 14, 5, 0]);
+        let err = almanac.seed_ranges().unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (16, "0"));
+        assert_eq!(err.expected, "a seed range length above zero");
+    }
+
+    #[test]
+    fn test_seed_range_overflow() {
+        let err = seeds_only("seeds: 18446744073709551615 5")
+            .seed_ranges()
+            .unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (29, "5"));
+        assert_eq!(err.expected, "a range within 64 bits");
+
+        let almanac = seeds_only("seeds: 18446744073709551610 5");
+        assert_eq!(almanac.seed_ranges(), Ok(vec![u64::MAX - 5..u64::MAX]));
+    }
+
+    #[test]
+    fn test_no_seeds() {
+        let err = extract_data("seeds:\n\nseed-to-location map:\n50 98 2").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 7));
+        assert_eq!(err.expected, "a seed");
+    }
+
+    #[test]
+    fn test_lines_after_seeds() {
+        let err =
+            extract_data("seeds: 79 14\n50 98 2\n\nseed-to-location map:\n50 98 2").unwrap_err();
+        assert_eq!((err.line, err.found.as_str()), (2, "50 98 2"));
+        assert_eq!(err.expected, "a blank line after the seeds");
+    }
+
+    #[test]
+    fn test_invalid_map_name() {
+        let err = extract_data("seeds: 79 14\n\nseed-dirt map:\n50 98 2").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.found, "seed-dirt");
+    }
+
+    #[test]
+    fn test_short_map_row() {
+        let err = extract_data("seeds: 79 14\n\nseed-to-soil map:\n50 98").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 1));
+        assert_eq!(err.expected, "three numbers");
+    }
+
+    #[test]
+    fn test_missing_map() {
+        let input = read_input(EXAMPLE);
+        let truncated = &input[..input.find("humidity-to-location").unwrap()];
+        let err = extract_data(truncated).unwrap_err();
+        assert_eq!(err.expected, "maps leading from seed to location");
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        let parse = |rules: &str| {
+            let input = format!("seeds: 1 2\n\nseed-to-location map:\n{}", rules);
+            extract_data(&input).unwrap_err()
+        };
+
+        let err = parse("0 10 10\n5 0 0\n");
+        assert_eq!((err.line, err.found.as_str()), (5, "5 0 0"));
+        assert_eq!(err.expected, "a rule with a length above zero");
+
+        let err = parse("0 18446744073709551615 2\n");
+        assert_eq!(
+            (err.line, err.expected.as_str()),
+            (4, "a range within 64 bits")
+        );
+        let err = parse("0 10 10\n18446744073709551615 0 2\n");
+        assert_eq!(
+            (err.line, err.expected.as_str()),
+            (5, "a range within 64 bits")
+        );
+
+        let err = parse("0 10 10\n0 30 5\n100 15 10\n");
+        assert_eq!((err.line, err.column), (6, 1));
+        assert_eq!(err.expected, "a source range clear of line 4");
+    }
+
+    #[test]
+    fn test_normalise_keeps_values() {
+        let map = seed_to_soil();
+        let normalised = map.normalise();
+        assert_eq!(normalised.rules.len(), 4);
+        assert!(normalised.validate().is_empty());
+        for input in 0..200 {
+            assert_eq!(normalised.compute_sorted(input), map.compute(input));
+        }
+    }
+
+    #[test]
+    fn test_duplicate_map() {
+        let input = "seeds: 1 2\n\nseed-to-location map:\n0 1 1\n\nseed-to-location map:\n";
+        let err = extract_data(input).unwrap_err();
+        assert_eq!((err.line, err.column), (6, 1));
+    }
+
+    #[test]
+    fn test_chain_between_any_stages() {
+        let input = read_input(EXAMPLE);
+        let almanac = extract_data(&input).unwrap();
+
+        let names = |chain: Chain| -> Vec<String> {
+            chain.maps.iter().map(|x| x.destination.clone()).collect()
+        };
+        let chain = almanac.chain("seed", "humidity").unwrap();
+        assert_eq!(chain.maps.len(), 6);
+        assert_eq!(chain.compute(79), 78);
+        let chain = almanac.chain("soil", "location").unwrap();
+        assert_eq!(chain.compute(81), 82);
+        assert_eq!(
+            names(almanac.chain("water", "temperature").unwrap()),
+            ["light", "temperature"]
+        );
+        assert!(almanac.chain("light", "light").unwrap().maps.is_empty());
+
+        // Maps only go one way
+        assert!(almanac.chain("location", "seed").is_none());
+        assert!(almanac.chain("seed", "weather").is_none());
+    }
+
+    #[test]
+    fn test_custom_stages() {
+        // An extra stage, renamed stages and maps listed out of order
+        let input = "seeds: 5 1\n\n\
+            dirt-to-location map:\n100 0 10\n\n\
+            seed-to-grain map:\n0 5 1\n\n\
+            grain-to-dirt map:\n7 0 1\n";
+        let almanac = extract_data(input).unwrap();
+        let chain = almanac.chain(SEED, LOCATION).unwrap();
+        assert_eq!(chain.maps.len(), 3);
+        assert_eq!(chain.compute(5), 107);
+        assert_eq!(chain.compute_ranges(&[5..6]), vec![107..108]);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let err = extract_data("").unwrap_err();
+        assert_eq!(err.expected, "a `seeds:` line");
+    }
+}
@@ -0,0 +1,34 @@
+//! https://adventofcode.com/2023/day/5
+
+use almanac::Almanac;
+use aoc_common::{ParseError, Solution};
+
+pub mod almanac;
+pub mod parallel;
+pub mod part1;
+pub mod part2;
+pub mod rule;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+    type Answer1 = u64;
+    type Answer2 = Result<u64, ParseError>;
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        almanac::extract_data(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> u64 {
+        part1::part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<u64, ParseError> {
+        part2::part2(input)
+    }
+}
@@ -0,0 +1,178 @@
+use crate::almanac::{Almanac, LOCATION, SEED};
+use aoc_common::ParseError;
+use std::ops::Range;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// The number of seeds a worker takes at a time.
+const CHUNK: u64 = 1 << 16;
+
+/// How many seeds a brute force has sent through the maps so far, in 128
+/// bits as several ranges can hold more than `u64::MAX` seeds between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    pub done: u128,
+    pub total: u128,
+}
+
+/// Finds the same answer as `part2::brute_force`, sending every seed through
+/// the maps but sharing the seeds out between `threads` worker threads.
+///
+/// Workers take chunks of seeds until none are left, calling `progress` after
+/// each, so one slow range doesn't hold the others up. Fails as
+/// `part2::part2` does, and a worker panicking panics the caller with it.
+pub fn brute_force<F>(almanac: &Almanac, threads: usize, progress: F) -> Result<u64, ParseError>
+where
+    F: Fn(Progress) + Sync,
+{
+    brute_force_chunked(almanac, threads, CHUNK, progress)
+}
+
+fn brute_force_chunked<F>(
+    almanac: &Almanac,
+    threads: usize,
+    chunk: u64,
+    progress: F,
+) -> Result<u64, ParseError>
+where
+    F: Fn(Progress) + Sync,
+{
+    let chain = almanac.chain(SEED, LOCATION).unwrap();
+    let chunks = shard(&almanac.seed_ranges()?, chunk);
+    let total = count(&chunks);
+
+    let next = AtomicUsize::new(0);
+    let done: Mutex<u128> = Mutex::new(0);
+
+    let location = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads.max(1))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut location: Option<u64> = None;
+                    while let Some(seeds) = chunks.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        let length = seeds.end - seeds.start;
+                        for seed in seeds.clone() {
+                            let loc = chain.compute(seed);
+                            if location.is_none() || loc < location.unwrap() {
+                                location = Some(loc);
+                            }
+                        }
+
+                        let done = {
+                            let mut done = done.lock().unwrap();
+                            *done += u128::from(length);
+                            *done
+                        };
+                        progress(Progress { done, total });
+                    }
+                    location
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .filter_map(|worker| {
+                worker
+                    .join()
+                    .unwrap_or_else(|err| panic::resume_unwind(err))
+            })
+            .min()
+    });
+    Ok(location.expect("seed ranges hold at least one seed"))
+}
+
+/// The number of values in all of `ranges`, which can be more than a `u64`
+/// holds.
+fn count(ranges: &[Range<u64>]) -> u128 {
+    ranges.iter().map(|x| u128::from(x.end - x.start)).sum()
+}
+
+/// Cuts ranges into pieces of at most `size` values, dropping empty ones.
+fn shard(ranges: &[Range<u64>], size: u64) -> Vec<Range<u64>> {
+    let mut chunks: Vec<Range<u64>> = Vec::new();
+    for range in ranges {
+        let mut start = range.start;
+        while start < range.end {
+            let end = range.end.min(start.saturating_add(size));
+            chunks.push(start..end);
+            start = end;
+        }
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::almanac::{extract_data, Seeds};
+    use crate::part2;
+    use aoc_common::read_input;
+
+    const EXAMPLE: &str = "input/example.txt";
+
+    #[test]
+    fn test_shard() {
+        assert_eq!(
+            shard(&[79..93, 55..55, 0..3], 5),
+            vec![79..84, 84..89, 89..93, 0..3]
+        );
+        assert_eq!(
+            shard(&[u64::MAX - 2..u64::MAX, 7..7], 5),
+            vec![u64::MAX - 2..u64::MAX]
+        );
+    }
+
+    #[test]
+    fn test_matches_serial() {
+        let almanac = extract_data(&read_input(EXAMPLE)).unwrap();
+        let serial = part2::brute_force(&almanac);
+
+        assert_eq!(brute_force(&almanac, 4, |_| {}), serial);
+        for threads in [1, 2, 3, 8] {
+            for chunk in [1, 3, 14, 100] {
+                let location = brute_force_chunked(&almanac, threads, chunk, |_| {});
+                assert_eq!(location, serial);
+            }
+        }
+    }
+
+    #[test]
+    fn test_count_past_64_bits() {
+        let ranges = [0..u64::MAX, 1..u64::MAX, 5..5];
+        assert_eq!(count(&ranges), 2 * u128::from(u64::MAX) - 1);
+    }
+
+    #[test]
+    fn test_no_seeds() {
+        let mut almanac = extract_data(&read_input(EXAMPLE)).unwrap();
+        almanac.seeds = Seeds::new(Vec::new());
+        let err = brute_force(&almanac, 4, |_| {}).unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (1, "a seed"));
+    }
+
+    #[test]
+    #[should_panic(expected = "progress failed")]
+    fn test_worker_panic() {
+        let almanac = extract_data(&read_input(EXAMPLE)).unwrap();
+        let _ = brute_force_chunked(&almanac, 2, 5, |_| panic!("progress failed"));
+    }
+
+    #[test]
+    fn test_progress() {
+        let almanac = extract_data(&read_input(EXAMPLE)).unwrap();
+        let reports: Mutex<Vec<Progress>> = Mutex::new(Vec::new());
+
+        let location = brute_force_chunked(&almanac, 3, 5, |x| reports.lock().unwrap().push(x));
+        assert_eq!(location, Ok(46));
+
+        // 14 and 13 seeds make 3 and 3 chunks of at most 5
+        let mut reports = reports.into_inner().unwrap();
+        reports.sort_by_key(|x| x.done);
+        assert_eq!(reports.len(), 6);
+        assert!(reports.iter().all(|x| x.total == 27));
+        assert_eq!(reports.last().unwrap().done, 27);
+    }
+}
@@ -0,0 +1,37 @@
+use crate::almanac::{Almanac, LOCATION, SEED};
+
+/// Returns the lowest location of any of the listed seeds.
+pub fn part1(almanac: &Almanac) -> u64 {
+    let chain = almanac.chain(SEED, LOCATION).unwrap();
+    let mut locations: Vec<u64> = Vec::new();
+
+    for seed in &almanac.seeds.seeds {
+        let location = chain.compute(*seed);
+        locations.push(location);
+    }
+
+    locations.iter().cloned().min().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::almanac::extract_data;
+    use aoc_common::read_input;
+
+    const EXAMPLE: &str = "input/example.txt";
+    const INPUT: &str = "input/input.txt";
+
+    #[test]
+    fn test_example_answer() {
+        let result = part1(&extract_data(&read_input(EXAMPLE)).unwrap());
+        assert_eq!(result, 35)
+    }
+
+    #[test]
+    #[ignore = "requires personal puzzle input"]
+    fn test_known_correct_answer() {
+        let result = part1(&extract_data(&read_input(INPUT)).unwrap());
+        assert_eq!(result, 165788812)
+    }
+}
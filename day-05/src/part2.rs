@@ -0,0 +1,104 @@
+use crate::almanac::{Almanac, LOCATION, SEED};
+use aoc_common::ParseError;
+
+/// Returns the lowest location of any seed in the listed seed ranges.
+///
+/// Fails when the seeds do not pair up into ranges of at least one seed.
+pub fn part2(almanac: &Almanac) -> Result<u64, ParseError> {
+    let chain = almanac.chain(SEED, LOCATION).unwrap();
+    let locations = chain.compute_ranges(&almanac.seed_ranges()?);
+
+    // The ranges come back sorted, so the first one starts at the lowest location
+    Ok(locations
+        .first()
+        .expect("seed ranges hold at least one seed")
+        .start)
+}
+
+/// Finds the same answer as `part2` by sending every single seed through the
+/// maps. Far too slow for a real input, but a useful cross-check.
+///
+/// Fails as `part2` does.
+pub fn brute_force(almanac: &Almanac) -> Result<u64, ParseError> {
+    let chain = almanac.chain(SEED, LOCATION).unwrap();
+    let mut location: Option<u64> = None;
+
+    for seed_range in almanac.seed_ranges()? {
+        for seed in seed_range {
+            let loc = chain.compute(seed);
+
+            if location.is_none() || loc < location.unwrap() {
+                location = Some(loc);
+            }
+        }
+    }
+
+    Ok(location.expect("seed ranges hold at least one seed"))
+}
+
+/// Finds the same answer as `part2` the other way round, by trying every
+/// location from 0 upwards until one leads back to a listed seed.
+///
+/// Fails as `part2` does.
+pub fn scan_locations(almanac: &Almanac) -> Result<u64, ParseError> {
+    let chain = almanac.chain(SEED, LOCATION).unwrap();
+    let seeds = almanac.seed_ranges()?;
+
+    let location = (0..)
+        .find(|location| {
+            chain
+                .invert(*location)
+                .iter()
+                .any(|seed| seeds.iter().any(|x| x.contains(seed)))
+        })
+        .unwrap();
+    Ok(location)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::almanac::extract_data;
+    use aoc_common::read_input;
+
+    const EXAMPLE: &str = "input/example.txt";
+    const INPUT: &str = "input/input.txt";
+
+    #[test]
+    fn test_example_answer() {
+        let result = part2(&extract_data(&read_input(EXAMPLE)).unwrap());
+        assert_eq!(result, Ok(46))
+    }
+
+    #[test]
+    fn test_example_brute_force() {
+        let almanac = extract_data(&read_input(EXAMPLE)).unwrap();
+        assert_eq!(brute_force(&almanac), part2(&almanac))
+    }
+
+    #[test]
+    fn test_example_scan_locations() {
+        let almanac = extract_data(&read_input(EXAMPLE)).unwrap();
+        assert_eq!(scan_locations(&almanac), Ok(46))
+    }
+
+    #[test]
+    fn test_part1_seeds() {
+        let input = read_input(EXAMPLE).replacen("seeds: 79 14 55 13", "seeds: 79 0", 1);
+        let almanac = extract_data(&input).unwrap();
+        assert_eq!(crate::part1::part1(&almanac), 22);
+
+        let err = part2(&almanac).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 11));
+        assert_eq!(err.expected, "a seed range length above zero");
+        assert_eq!(brute_force(&almanac), Err(err.clone()));
+        assert_eq!(scan_locations(&almanac), Err(err));
+    }
+
+    #[test]
+    #[ignore = "requires personal puzzle input"]
+    fn test_known_correct_answer() {
+        let result = part2(&extract_data(&read_input(INPUT)).unwrap());
+        assert_eq!(result, Ok(165788812))
+    }
+}
@@ -0,0 +1,325 @@
+use std::fmt;
+use std::ops::Range;
+
+/// One row of an almanac map, sending the `len` values from `src` onwards to
+/// the `len` values from `dst` onwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    pub dst: u64,
+    pub src: u64,
+    pub len: u64,
+}
+
+impl Rule {
+    pub fn new(dst: u64, src: u64, len: u64) -> Rule {
+        Rule { dst, src, len }
+    }
+
+    /// A rule leaving every value of `range` as it is.
+    pub fn identity(range: Range<u64>) -> Rule {
+        Rule::new(range.start, range.start, range.end - range.start)
+    }
+
+    /// The values the rule reads, or `None` when they run past `u64::MAX`.
+    pub fn checked_source(&self) -> Option<Range<u64>> {
+        Some(self.src..self.src.checked_add(self.len)?)
+    }
+
+    /// The values the rule writes, or `None` when they run past `u64::MAX`.
+    pub fn checked_destination(&self) -> Option<Range<u64>> {
+        Some(self.dst..self.dst.checked_add(self.len)?)
+    }
+
+    /// The values the rule reads.
+    ///
+    /// Panics when they run past `u64::MAX`, which `validate` rules out.
+    pub fn source(&self) -> Range<u64> {
+        self.checked_source()
+            .unwrap_or_else(|| panic!("Rule {} overflows u64", self))
+    }
+
+    /// The values the rule writes.
+    ///
+    /// Panics when they run past `u64::MAX`, which `validate` rules out.
+    pub fn destination(&self) -> Range<u64> {
+        self.checked_destination()
+            .unwrap_or_else(|| panic!("Rule {} overflows u64", self))
+    }
+
+    /// Where the rule sends `input`, or `None` if it doesn't read it.
+    pub fn apply(&self, input: u64) -> Option<u64> {
+        if self.source().contains(&input) {
+            Some(self.dst + (input - self.src))
+        } else {
+            None
+        }
+    }
+
+    /// Which value the rule sends onto `output`, or `None` if it doesn't
+    /// write it.
+    pub fn unapply(&self, output: u64) -> Option<u64> {
+        if self.destination().contains(&output) {
+            Some(self.src + (output - self.dst))
+        } else {
+            None
+        }
+    }
+
+    /// Whether the rule leaves every value it reads as it is.
+    pub fn is_identity(&self) -> bool {
+        self.dst == self.src
+    }
+}
+
+/// Written the way the almanac lists it, eg `50 98 2`.
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.dst, self.src, self.len)
+    }
+}
+
+/// A mistake in a list of rules, pointing at rules by their index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleError {
+    /// The rule reads or writes values past `u64::MAX`.
+    Overflow { rule: usize },
+    /// The rule has a length of zero, so does nothing.
+    Empty { rule: usize },
+    /// The rule reads values an earlier listed rule already reads.
+    Overlap { rule: usize, other: usize },
+}
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RuleError::Overflow { rule } => write!(f, "rule {} runs past {}", rule, u64::MAX),
+            RuleError::Empty { rule } => write!(f, "rule {} has a length of zero", rule),
+            RuleError::Overlap { rule, other } => {
+                write!(f, "rule {} reads values rule {} already reads", rule, other)
+            }
+        }
+    }
+}
+
+/// Checks a list of rules for overflows, empty rules and overlapping source
+/// ranges, returning every mistake found ordered by rule.
+pub fn validate(rules: &[Rule]) -> Vec<RuleError> {
+    let mut errors: Vec<RuleError> = Vec::new();
+    let mut sources: Vec<(usize, Range<u64>)> = Vec::new();
+
+    for (index, rule) in rules.iter().enumerate() {
+        if rule.len == 0 {
+            errors.push(RuleError::Empty { rule: index });
+            continue;
+        }
+        match (rule.checked_source(), rule.checked_destination()) {
+            (Some(source), Some(_)) => sources.push((index, source)),
+            _ => errors.push(RuleError::Overflow { rule: index }),
+        }
+    }
+
+    // Sorted by start, a source overlaps an earlier one exactly when it starts
+    // before the furthest end seen so far
+    sources.sort_by_key(|(index, source)| (source.start, *index));
+    let mut furthest: Option<(usize, u64)> = None;
+    for (index, source) in sources {
+        if let Some((other, end)) = furthest {
+            if source.start < end {
+                let (rule, other) = (index.max(other), index.min(other));
+                errors.push(RuleError::Overlap { rule, other });
+            }
+        }
+        if furthest.is_none_or(|(_, end)| source.end > end) {
+            furthest = Some((index, source.end));
+        }
+    }
+
+    errors.sort_by_key(|error| match *error {
+        RuleError::Overflow { rule } | RuleError::Empty { rule } => (rule, 0),
+        RuleError::Overlap { rule, other } => (rule, other + 1),
+    });
+    errors
+}
+
+/// Sorts rules by source and fills every gap between them with an identity
+/// rule, so that the result reads every value below `u64::MAX` exactly once.
+///
+/// Empty rules are dropped. Rules are expected to pass `validate`, but where
+/// two overlap the one listed first keeps the values both read, as it is the
+/// one `Map::compute` applies.
+pub fn normalise(rules: &[Rule]) -> Vec<Rule> {
+    let mut pieces: Vec<Rule> = Vec::new();
+    for rule in rules.iter().filter(|x| x.len > 0) {
+        let mut unread = vec![rule.source()];
+        for piece in &pieces {
+            unread = unread
+                .iter()
+                .flat_map(|range| subtract(range, &piece.source()))
+                .collect();
+        }
+        pieces.extend(unread.into_iter().map(|range| {
+            Rule::new(
+                rule.dst + (range.start - rule.src),
+                range.start,
+                range.end - range.start,
+            )
+        }));
+    }
+    pieces.sort_by_key(|x| x.src);
+
+    let mut normalised: Vec<Rule> = Vec::new();
+    let mut next = 0;
+    for piece in pieces {
+        if next < piece.src {
+            normalised.push(Rule::identity(next..piece.src));
+        }
+        next = piece.src + piece.len;
+        normalised.push(piece);
+    }
+    if next < u64::MAX {
+        normalised.push(Rule::identity(next..u64::MAX));
+    }
+
+    normalised
+}
+
+/// Returns the parts of `range` outside `other`.
+fn subtract(range: &Range<u64>, other: &Range<u64>) -> Vec<Range<u64>> {
+    [
+        range.start..range.end.min(other.start),
+        range.start.max(other.end)..range.end,
+    ]
+    .into_iter()
+    .filter(|x| !x.is_empty())
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let rule = Rule::new(52, 50, 48);
+        assert_eq!(rule.apply(49), None);
+        assert_eq!(rule.apply(50), Some(52));
+        assert_eq!(rule.apply(97), Some(99));
+        assert_eq!(rule.apply(98), None);
+        assert_eq!(rule.unapply(99), Some(97));
+        assert_eq!(rule.unapply(100), None);
+    }
+
+    #[test]
+    fn test_checked_ranges() {
+        assert_eq!(
+            Rule::new(0, u64::MAX - 2, 2).checked_source(),
+            Some(u64::MAX - 2..u64::MAX)
+        );
+        assert_eq!(Rule::new(0, u64::MAX - 2, 3).checked_source(), None);
+        assert_eq!(Rule::new(u64::MAX, 0, 1).checked_destination(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows u64")]
+    fn test_source_overflow_panics() {
+        Rule::new(0, u64::MAX, 5).apply(0);
+    }
+
+    #[test]
+    fn test_validate_valid() {
+        let rules = vec![Rule::new(50, 98, 2), Rule::new(52, 50, 48)];
+        assert!(validate(&rules).is_empty());
+    }
+
+    #[test]
+    fn test_validate() {
+        let rules = vec![
+            Rule::new(0, 10, 10),
+            Rule::new(0, 5, 0),
+            Rule::new(u64::MAX - 1, 0, 2),
+            Rule::new(100, 15, 10),
+            Rule::new(200, 0, 11),
+        ];
+        assert_eq!(
+            validate(&rules),
+            vec![
+                RuleError::Empty { rule: 1 },
+                RuleError::Overflow { rule: 2 },
+                RuleError::Overlap { rule: 3, other: 0 },
+                RuleError::Overlap { rule: 4, other: 0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_overlap_with_long_rule() {
+        // The third rule starts after the second ends, but inside the first
+        let rules = vec![
+            Rule::new(0, 0, 100),
+            Rule::new(0, 10, 5),
+            Rule::new(0, 20, 5),
+        ];
+        assert_eq!(
+            validate(&rules),
+            vec![
+                RuleError::Overlap { rule: 1, other: 0 },
+                RuleError::Overlap { rule: 2, other: 0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_error_display() {
+        assert_eq!(
+            RuleError::Overlap { rule: 3, other: 1 }.to_string(),
+            "rule 3 reads values rule 1 already reads"
+        );
+    }
+
+    #[test]
+    fn test_normalise() {
+        let rules = vec![
+            Rule::new(50, 98, 2),
+            Rule::new(7, 3, 0),
+            Rule::new(52, 50, 48),
+        ];
+        assert_eq!(
+            normalise(&rules),
+            vec![
+                Rule::identity(0..50),
+                Rule::new(52, 50, 48),
+                Rule::new(50, 98, 2),
+                Rule::identity(100..u64::MAX),
+            ]
+        );
+        assert_eq!(normalise(&[]), vec![Rule::identity(0..u64::MAX)]);
+        assert_eq!(
+            normalise(&[Rule::new(1, 0, u64::MAX - 1)]),
+            vec![
+                Rule::new(1, 0, u64::MAX - 1),
+                Rule::identity(u64::MAX - 1..u64::MAX)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalise_overlap() {
+        // Values 10 to 14 are read by both, and the first listed rule wins
+        let rules = vec![Rule::new(100, 10, 10), Rule::new(200, 5, 10)];
+        let normalised = normalise(&rules);
+        assert_eq!(
+            normalised,
+            vec![
+                Rule::identity(0..5),
+                Rule::new(200, 5, 5),
+                Rule::new(100, 10, 10),
+                Rule::identity(20..u64::MAX),
+            ]
+        );
+        for value in 0..30 {
+            let expected = rules.iter().find_map(|x| x.apply(value)).unwrap_or(value);
+            let actual = normalised.iter().find_map(|x| x.apply(value)).unwrap();
+            assert_eq!(actual, expected, "{}", value);
+        }
+    }
+}